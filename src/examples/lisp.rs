@@ -3,7 +3,8 @@ use parser_combinator::{
    parsers::{
       digits_parser::Digits, 
       str_parser::Str, 
-      between_parser::Between
+      between_parser::Between,
+      cut_parser::Cut
    }, 
    collection_parsers::{
       choice_parser::Choice, 
@@ -13,8 +14,10 @@ use parser_combinator::{
    models::{
       parser::Parser, 
       parser_traits::Parse, 
-      state::{State, ParserResult}, cardinality::Cardinality::{self, One}
-   }, parser_helpers::map_result
+      state::{State, ParserResult}, cardinality::Cardinality::{self, One},
+      parse_error::ParseError
+   }, parser_helpers::map_result,
+   diagnostics::Diagnostic
 };
 
 
@@ -23,7 +26,7 @@ type Ptr<T> = Rc<RefCell<T>>;
 type TokenParser<'a> = Parser<'a, String, Token, &'a str>;
 type TokenSeq<'a> = SequenceOf<String,Token,&'a str>;
 type TokenChoice<'a> = Choice<String,Token,&'a str>;
-type TokenRes = Result<Cardinality<Token>, String>;
+type TokenRes = Result<Cardinality<Token>, ParseError>;
 
 #[derive(Debug, Clone, Copy)]
 enum Operation {
@@ -33,6 +36,7 @@ enum Operation {
    Multiply
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 enum Token {
    Op(Operation),
@@ -42,14 +46,15 @@ enum Token {
 }
 
 fn map_string_parser<'a>(parser: Str) -> TokenParser<'a> {
-   return map_result(parser, |result: ParserResult<String>| {
+   map_result(parser, |result: ParserResult<String>| {
       let res: TokenRes = match result {
          Some(Ok(One(res))) => Ok(One(Token::String(res))),
-         _ => Err("Failed to parse string".to_owned())
+         Some(Err(err)) => Err(err),
+         _ => Err(ParseError::custom("Token", 0, "Failed to parse string".to_owned()))
       };
    
       Some(res)
-   });
+   })
 }
 
 fn map_digit_parser<'a> (digits: Digits) -> TokenParser<'a> {
@@ -59,7 +64,7 @@ fn map_digit_parser<'a> (digits: Digits) -> TokenParser<'a> {
             Some(Ok(One(res))) => 
                Ok(One(Token::Number(res.parse::<f64>().unwrap()))),
             Some(Err(err)) => Err(err),
-            _ => Err("Failed to parse digits".to_owned())
+            _ => Err(ParseError::custom("Token", 0, "Failed to parse digits".to_owned()))
          };
 
          Some(res)
@@ -118,16 +123,16 @@ pub fn main() {
          use Token::Op;
          use Operation::{Add, Minus, Multiply, Divide};
 
-         let res: TokenRes = if let Some(Ok(One(res))) = result {
-            match res.as_str() {
+         let res: TokenRes = match result {
+            Some(Ok(One(res))) => match res.as_str() {
                "+" => Ok(One(Op(Add))),
                "-" => Ok(One(Op(Minus))),
                "*" => Ok(One(Op(Multiply))),
                "/" => Ok(One(Op(Divide))),
                _ => panic!("Invalid Operation")
-            }
-         } else {
-            panic!("Unkown Operation");
+            },
+            Some(Err(err)) => Err(err),
+            _ => panic!("Unkown Operation")
          };
 
          Some(res)
@@ -141,14 +146,19 @@ pub fn main() {
    let left_bracket: TokenParser = map_string_parser(Str::new("(".to_owned()));
    let right_bracket: TokenParser = map_string_parser(Str::new(")".to_owned()));
 
+   // once a bracket is open the expression must be an operation
    let operation = Between::new(
       Box::new(left_bracket),
       Box::new(right_bracket),
-      Box::new(operation_sequence));
+      Box::new(Cut::new(operation_sequence)));
 
    expr.borrow_mut().push_parser(Box::new(operation));
    let res = expr.borrow().run("(+ 1 2 (/ 5 2) (* 2 5) (- 1 5))");
 
+   if let Some(report) = Diagnostic::new("lisp", false).render(&res) {
+      println!("{}", report);
+   }
+
    if let Some(Ok(Cardinality::Many(vals))) = res.result {
 
       let result = eval(&Token::Statement(vals));
@@ -170,23 +180,23 @@ fn eval(root_token: &Token) -> f64 {
    
          match operation {
             Add => {
-               total = rest.into_iter()
+               total = rest.iter()
                   .fold(0_f64, |prev, curr| prev + eval(curr));
             },
             Minus => {
                total = rest
-                  .into_iter()
+                  .iter()
                   .rev()
                   .fold(0_f64, |prev, curr| eval(curr) - prev);
             },
             Multiply => {
                total = rest
-                  .into_iter()
+                  .iter()
                   .fold(1_f64, |prev, curr| eval(curr) * prev);
             },
             Divide => {
                total = rest
-                  .into_iter()
+                  .iter()
                   .rev()
                   .fold(1_f64, |prev, curr| eval(curr) / prev);
             }   
@@ -1,8 +1,64 @@
+use crate::models::{input::Input, parser_traits::Parse, state::State};
+use crate::parsers::out_of_input;
 
-pub struct Bit {
+use super::{Bits, consume_bits};
 
-}
+/// # Bit:
+/// Parse a single bit of a [`Bits`] target, fails only at the end of input
+///
+/// ### Returns:
+/// The bit, 0 or 1, as a `u64` so it can be combined with the other bit parsers
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::bit_parsers::{Bits, bit_parser::Bit};
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target = Bits::new(&[0b1000_0000u8][..]);
+/// let result = Bit::new().run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), 1);
+/// assert_eq!(result.index, 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Bit {}
 
 impl Bit {
-   
+   pub fn new() -> Self {
+      Self {}
+   }
+}
+
+impl<T: Input> Parse<u64, u64, Bits<T>> for Bit {
+   fn transform(&self, state: State<u64, Bits<T>>) -> State<u64, Bits<T>> {
+      if state.is_error() {
+         return state;
+      }
+
+      match state.target.bit_at(state.index) {
+         Some(bit) => consume_bits(state, 1, bit as u64),
+         None => {
+            let err = out_of_input(&state.target.bytes, "Bit", state.index, "a bit".to_owned(), 1);
+            state.new_err(err)
+         }
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use super::{Bit, Bits};
+
+   #[test]
+   fn bit_end_of_input() {
+      let target = Bits::with_offset(&[0xffu8][..], 8);
+      let err = Bit::new().run(target).result.unwrap().unwrap_err();
+
+      assert!(err.is_end_of_input());
+      assert_eq!(err.index, 0);
+   }
 }
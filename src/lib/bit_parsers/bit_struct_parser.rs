@@ -0,0 +1,241 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::models::{cardinality::Cardinality::One, input::Input, parser_traits::Parse, state::State};
+
+use super::{Bits, uint_parser::Uint};
+
+/// # FromBits
+/// The structs read by the [`BitStruct`] parser, a list of unsigned fields
+/// packed one after the other. Implemented by the [`crate::bit_struct`] macro
+pub trait FromBits: Sized + fmt::Debug {
+   /// The name of the struct in the errors
+   const NAME: &'static str;
+   /// The name and size in bits of each field, in the order they are read
+   const FIELDS: &'static [(&'static str, usize)];
+
+   /// Build the struct from the value of each field
+   fn from_fields(values: &[u64]) -> Self;
+}
+
+/// # BitStruct:
+/// Parse the fields of a struct `S` declared with [`crate::bit_struct`]
+/// from a [`Bits`] target, each one with a big endian [`Uint`] parser.
+/// A field that fails is named in the context of the error
+///
+/// ### Returns:
+/// The struct with the value of each field
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::bit_parsers::{Bits, bit_struct_parser::BitStruct};
+/// use parser_combinator::bit_struct;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// bit_struct! {
+///    /// The first 32 bits of an IPv4 header
+///    pub Header { version: 4, ihl: 4, dscp: 6, ecn: 2, len: 16 }
+/// }
+///
+/// let target = Bits::new(&[0x45u8, 0x02, 0x00, 0x54][..]);
+/// let header = BitStruct::<Header>::new().run(target).result.unwrap().unwrap().unwrap_one();
+///
+/// assert_eq!(header, Header { version: 4, ihl: 5, dscp: 0, ecn: 2, len: 84 });
+/// assert_eq!(Header::BITS, 32);
+/// ```
+pub struct BitStruct<S> {
+   _s: PhantomData<S>
+}
+
+impl<S: FromBits> BitStruct<S> {
+   pub fn new() -> Self {
+      Self { _s: PhantomData }
+   }
+}
+
+impl<S: FromBits> Default for BitStruct<S> {
+   fn default() -> Self {
+      Self::new()
+   }
+}
+
+impl<S> Clone for BitStruct<S> {
+   fn clone(&self) -> Self {
+      Self { _s: PhantomData }
+   }
+}
+
+impl<S: FromBits> fmt::Debug for BitStruct<S> {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      write!(f, "BitStruct<{}>", S::NAME)
+   }
+}
+
+impl<T: Input, S: FromBits> Parse<S, S, Bits<T>> for BitStruct<S> {
+   fn transform(&self, state: State<S, Bits<T>>) -> State<S, Bits<T>> {
+      if state.is_error() {
+         return state;
+      }
+
+      let mut values = Vec::with_capacity(S::FIELDS.len());
+      let mut field_state: State<u64, Bits<T>> = state.reset();
+
+      for (field, bits) in S::FIELDS {
+         let next = Uint::new(*bits).transform(field_state);
+         field_state = next.reset();
+
+         match next.result.unwrap() {
+            Ok(value) => values.push(value.unwrap_one()),
+            Err(mut err) => {
+               err.context.insert(0, field.to_string());
+               err.context.insert(0, S::NAME.to_owned());
+               return field_state.forward_err(err);
+            }
+         }
+      }
+
+      State {
+         index: field_state.index,
+         target: field_state.target,
+         position: field_state.position,
+         result: Some(Ok(One(S::from_fields(&values)))),
+         tracker: field_state.tracker
+      }
+   }
+}
+
+/// # bit_struct!
+/// Declare a struct of unsigned fields packed in the given number of bits,
+/// read with a [`BitStruct`] parser in the order they are declared. Each
+/// field is a `u64` and must be 1 to 64 bits wide, which is checked at
+/// compile time. The struct derives `Debug`, `Clone`, `Copy`, `PartialEq`,
+/// `Eq` and `Default`, and has a `BITS` constant with its total size
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::bit_parsers::Bits;
+/// use parser_combinator::bit_struct;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// bit_struct! {
+///    /// The flags byte of a DNS header
+///    pub Flags {
+///       /// Set in responses
+///       qr: 1,
+///       opcode: 4,
+///       aa: 1,
+///       tc: 1,
+///       rd: 1
+///    }
+/// }
+///
+/// let flags = Flags::parser().run(Bits::new(&[0x81u8][..])).result.unwrap().unwrap().unwrap_one();
+///
+/// assert_eq!(flags.qr, 1);
+/// assert_eq!(flags.rd, 1);
+/// ```
+#[macro_export]
+macro_rules! bit_struct {
+   (
+      $(#[$meta: meta])*
+      $vis: vis $name: ident {
+         $(
+            $(#[$field_meta: meta])*
+            $field: ident : $bits: expr
+         ),+ $(,)?
+      }
+   ) => {
+      $(#[$meta])*
+      #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+      $vis struct $name {
+         $(
+            $(#[$field_meta])*
+            pub $field: u64,
+         )+
+      }
+
+      const _: () = {
+         $(
+            assert!(
+               $bits >= 1 && $bits <= 64,
+               concat!("bit_struct: the field ", stringify!($field), " must be between 1 and 64 bits")
+            );
+         )+
+      };
+
+      // the helpers of a private struct may go unused
+      #[allow(dead_code)]
+      impl $name {
+         /// The size of the struct in bits
+         pub const BITS: usize = 0 $(+ $bits)+;
+
+         /// Instantiate a parser reading the struct
+         pub fn parser() -> $crate::bit_parsers::bit_struct_parser::BitStruct<Self> {
+            $crate::bit_parsers::bit_struct_parser::BitStruct::new()
+         }
+      }
+
+      impl $crate::bit_parsers::bit_struct_parser::FromBits for $name {
+         const NAME: &'static str = stringify!($name);
+         const FIELDS: &'static [(&'static str, usize)] = &[$((stringify!($field), $bits)),+];
+
+         fn from_fields(values: &[u64]) -> Self {
+            let mut values = values.iter().copied();
+            Self {
+               $($field: values.next().expect("bit_struct: missing field value"),)+
+            }
+         }
+      }
+   };
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::bit_parsers::Bits;
+   use crate::collection_parsers::many_parser::Many;
+   use crate::models::parser_traits::Parse;
+
+   bit_struct! {
+      Header { version: 4, ihl: 4, dscp: 6, ecn: 2, len: 16 }
+   }
+
+   bit_struct! {
+      Wide { low: 1, full: 64, high: 7 }
+   }
+
+   #[test]
+   fn parses_fields_in_order() {
+      let target = Bits::new(&[0x45u8, 0xb9, 0x05, 0xdc, 0x46, 0x00, 0x00, 0x00][..]);
+      let res = Many::new(Header::parser()).run(target);
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_many(), vec![
+         Header { version: 4, ihl: 5, dscp: 46, ecn: 1, len: 1500 },
+         Header { version: 4, ihl: 6, dscp: 0, ecn: 0, len: 0 }
+      ]);
+      assert_eq!(res.index, 64);
+   }
+
+   #[test]
+   fn fields_across_bytes() {
+      let mut bytes = vec![0x80u8];
+      bytes.extend([0xff; 8]);
+      let wide = Wide::parser().run(Bits::new(&bytes[..])).result.unwrap().unwrap().unwrap_one();
+
+      assert_eq!(Wide::BITS, 72);
+      assert_eq!(wide, Wide { low: 1, full: u64::MAX >> 7, high: 0x7f });
+   }
+
+   #[test]
+   fn failing_field_in_context() {
+      let bits = Bits::new(&[0x45u8, 0xb9, 0x05][..]);
+      let err = Header::parser().run(bits).result.unwrap().unwrap_err();
+
+      assert!(err.is_end_of_input());
+      assert_eq!(err.context, vec!["Header", "len"]);
+      assert_eq!(err.index, 16);
+   }
+}
@@ -0,0 +1,92 @@
+use crate::models::{endian::Endian, input::Input, parser_traits::Parse, state::State};
+use crate::parsers::out_of_input;
+
+use super::{Bits, check_field_size, consume_bits, read_bits};
+
+/// # Int:
+/// Parse a two's complement signed integer field of 1 to 64 bits of a
+/// [`Bits`] target, most significant bit first unless built with
+/// [`Endian::Little`]
+///
+/// ### Returns:
+/// The value of the field
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::bit_parsers::{Bits, int_parser::Int};
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target = Bits::new(&[0xffu8, 0xe0][..]);
+/// let result = Int::new(12).run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), -2);
+/// assert_eq!(result.index, 12);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Int {
+   pub bits: usize,
+   pub endian: Endian
+}
+
+impl Int {
+   /// Instantiate a big endian [`Int`] parser of `bits` bits, panics
+   /// unless `bits` is between 1 and 64
+   pub fn new(bits: usize) -> Self {
+      Self::with_endian(bits, Endian::Big)
+   }
+
+   pub fn with_endian(bits: usize, endian: Endian) -> Self {
+      check_field_size("Int", bits);
+      Self { bits, endian }
+   }
+}
+
+impl<T: Input> Parse<i64, i64, Bits<T>> for Int {
+   fn transform(&self, state: State<i64, Bits<T>>) -> State<i64, Bits<T>> {
+      if state.is_error() {
+         return state;
+      }
+
+      match read_bits(&state.target, state.index, self.bits, self.endian) {
+         Some(value) => {
+            // extend the sign bit over the bits above the field
+            let shift = 64 - self.bits;
+            let value = ((value << shift) as i64) >> shift;
+            consume_bits(state, self.bits, value)
+         },
+         None => {
+            let expected = format!("a {} bit signed integer", self.bits);
+            let left = state.target.bit_len().saturating_sub(state.index);
+            let needed = (self.bits - left).div_ceil(8);
+            let err = out_of_input(&state.target.bytes, "Int", state.index, expected, needed);
+            state.new_err(err)
+         }
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use crate::models::endian::Endian;
+   use super::{Bits, Int};
+
+   #[test]
+   fn int_sign() {
+      let target = Bits::new(&[0b0111_1000u8][..]);
+
+      assert_eq!(Int::new(4).run(target.clone()).result.unwrap().unwrap().unwrap_one(), 7);
+      assert_eq!(Int::new(5).run(target.clone()).result.unwrap().unwrap().unwrap_one(), 15);
+      assert_eq!(Int::new(1).run(Bits::new(&[0x80u8][..])).result.unwrap().unwrap().unwrap_one(), -1);
+      assert_eq!(Int::with_endian(4, Endian::Little).run(target).result.unwrap().unwrap().unwrap_one(), -2);
+   }
+
+   #[test]
+   fn int_full_width() {
+      let target = Bits::new(&[0x80u8, 0, 0, 0, 0, 0, 0, 0][..]);
+      assert_eq!(Int::new(64).run(target).result.unwrap().unwrap().unwrap_one(), i64::MIN);
+   }
+}
@@ -1,7 +1,150 @@
-
 pub mod bit_parser;
 pub mod int_parser;
 pub mod uint_parser;
 pub mod one_parser;
 pub mod zero_parser;
-pub mod raw_string_parser;
\ No newline at end of file
+pub mod raw_string_parser;
+pub mod bit_struct_parser;
+
+use crate::models::{cardinality::Cardinality::One, endian::Endian, input::Input, state::State};
+
+/// # Bits
+/// A binary target read bit by bit, starting `offset` bits into `bytes`.
+/// The index of a state running on it is the index of the next bit rather
+/// than a byte offset, and the bits of each byte are read from the most
+/// significant one, the order of network protocols
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::bit_parsers::Bits;
+///
+/// let bits = Bits::with_offset(&[0b1010_0000u8, 0xff][..], 2);
+///
+/// assert_eq!(bits.bit_len(), 14);
+/// assert_eq!(bits.bit_at(0), Some(true));
+/// assert_eq!(bits.bit_at(1), Some(false));
+/// assert_eq!(bits.bit_at(14), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bits<T> {
+   pub bytes: T,
+   /// The bit of `bytes` the target starts at
+   pub offset: usize
+}
+
+impl<T: Input> Bits<T> {
+   pub fn new(bytes: T) -> Self {
+      Self { bytes, offset: 0 }
+   }
+
+   /// Instantiate a target starting `offset` bits into `bytes`, an offset
+   /// past the end of `bytes` gives an empty target
+   pub fn with_offset(bytes: T, offset: usize) -> Self {
+      Self { bytes, offset }
+   }
+
+   /// The number of bits of the target
+   pub fn bit_len(&self) -> usize {
+      (self.bytes.len() * 8).saturating_sub(self.offset)
+   }
+
+   /// The bit at `index`, `None` at the end of the target
+   pub fn bit_at(&self, index: usize) -> Option<bool> {
+      let bit = self.offset + index;
+      self.bytes
+         .byte_at(bit / 8)
+         .map(|byte| (byte >> (7 - bit % 8)) & 1 == 1)
+   }
+}
+
+/// Read the `count` bits of `target` from `index` as an unsigned value,
+/// `None` if the target ends first. With [`Endian::Big`] the first bit read
+/// is the most significant bit of the value, with [`Endian::Little`] the
+/// least significant one
+pub(crate) fn read_bits<T: Input>(target: &Bits<T>, index: usize, count: usize, endian: Endian) -> Option<u64> {
+   if index + count > target.bit_len() {
+      return None;
+   }
+
+   let mut value = 0u64;
+   for i in 0..count {
+      let bit = target.bit_at(index + i)? as u64;
+      match endian.resolve() {
+         Endian::Little => value |= bit << i,
+         _ => value = value << 1 | bit
+      }
+   }
+
+   Some(value)
+}
+
+/// Panics unless `bits` is the size of a field that fits in a `u64`
+pub(crate) fn check_field_size(parser: &str, bits: usize) {
+   if !(1..=64).contains(&bits) {
+      panic!("{}: the size of the field must be between 1 and 64 bits, got {}", parser, bits)
+   }
+}
+
+/// Consume `count` bits of the target from the index of `state`, returning
+/// `value` as the result
+pub(crate) fn consume_bits<R, T>(state: State<R, Bits<T>>, count: usize, value: R) -> State<R, Bits<T>> {
+   State {
+      index: state.index + count,
+      position: state.position,
+      result: Some(Ok(One(value))),
+      target: state.target,
+      tracker: state.tracker
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::{choice_parser::Choice, many_parser::Many, sequence_of_parser::SequenceOf};
+   use crate::models::parser_traits::Parse;
+   use super::Bits;
+   use super::{bit_parser::Bit, one_parser::One, zero_parser::Zero};
+
+   #[test]
+   fn parses_flags() {
+      // a 0 marker bit followed by three flags
+      let flags = SequenceOf::new(vec![
+         Box::new(Zero::new()),
+         Box::new(Bit::new()),
+         Box::new(Bit::new()),
+         Box::new(Bit::new())
+      ]);
+      let res = flags.run(Bits::new(&[0b0101_0000u8][..]));
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_many(), vec![0, 1, 0, 1]);
+      assert_eq!(res.index, 4);
+   }
+
+   #[test]
+   fn counts_leading_ones() {
+      let ones = Many::new(One::new());
+      let res = ones.run(Bits::new(&[0b1110_1111u8][..]));
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_many().len(), 3);
+      assert_eq!(res.index, 3);
+   }
+
+   #[test]
+   fn choice_between_bits() {
+      let either = Choice::new(vec![Box::new(One::new()), Box::new(Zero::new())]);
+      let res = either.run(Bits::with_offset(&[0b0000_0010u8][..], 6));
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_one(), 1);
+      assert_eq!(res.index, 1);
+   }
+
+   #[test]
+   fn offset_past_the_end() {
+      let target = Bits::with_offset(&[0u8][..], 16);
+      let res = Bit::new().run(target.clone());
+
+      assert_eq!(target.bit_len(), 0);
+      assert_eq!(target.bit_at(0), None);
+      assert!(res.result.unwrap().unwrap_err().is_end_of_input());
+   }
+}
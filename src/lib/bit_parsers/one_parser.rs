@@ -0,0 +1,55 @@
+use crate::models::{input::Input, parse_error::ParseError, parser_traits::Parse, state::State};
+use crate::parsers::out_of_input;
+
+use super::{Bits, consume_bits};
+
+/// # One:
+/// Parse a single 1 bit of a [`Bits`] target
+///
+/// ### Returns:
+/// The bit, 1, as a `u64` so it can be combined with the other bit parsers
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::bit_parsers::{Bits, one_parser::One};
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let result = One::new().run(Bits::new(&[0b1000_0000u8][..]));
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), 1);
+///
+/// let result = One::new().run(Bits::new(&[0b0111_1111u8][..]));
+/// assert!(result.result.unwrap().unwrap_err().is_mismatch());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct One {}
+
+impl One {
+   pub fn new() -> Self {
+      Self {}
+   }
+}
+
+impl<T: Input> Parse<u64, u64, Bits<T>> for One {
+   fn transform(&self, state: State<u64, Bits<T>>) -> State<u64, Bits<T>> {
+      if state.is_error() {
+         return state;
+      }
+
+      let expected = "a 1 bit".to_owned();
+
+      match state.target.bit_at(state.index) {
+         Some(true) => consume_bits(state, 1, 1),
+         Some(_) => {
+            let err = ParseError::mismatch("One", state.index, expected, "0".to_owned());
+            state.new_err(err)
+         },
+         None => {
+            let err = out_of_input(&state.target.bytes, "One", state.index, expected, 1);
+            state.new_err(err)
+         }
+      }
+   }
+}
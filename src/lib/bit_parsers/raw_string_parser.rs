@@ -0,0 +1,121 @@
+use crate::byte_parsers::escape;
+use crate::models::{cardinality::Cardinality::Many, endian::Endian, input::Input, parse_error::ParseError, parser_traits::Parse, state::State};
+use crate::parsers::out_of_input;
+
+use super::{Bits, read_bits};
+
+/// # RawString:
+/// Parse a byte string of a [`Bits`] target, whatever the alignment of its
+/// first bit. Built with [`RawString::take`] it matches any `len` bytes
+///
+/// ### Returns:
+/// The bytes, one `u64` per byte so they can be combined with the other
+/// bit parsers in a `SequenceOf`
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::bit_parsers::{Bits, bit_parser::Bit, raw_string_parser::RawString};
+/// use parser_combinator::collection_parsers::sequence_of_parser::SequenceOf;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// // a flag followed by "GET" on the next 24 bits
+/// let target = Bits::new(&[0b1010_0011, 0b1010_0010, 0b1010_1010, 0b0000_0000][..]);
+/// let parser = SequenceOf::new(vec![Box::new(Bit::new()), Box::new(RawString::new("GET"))]);
+/// let result = parser.run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_many(), vec![1, 71, 69, 84]);
+/// assert_eq!(result.index, 25);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RawString {
+   /// The bytes to match, `None` to match any `len` bytes
+   pub to_match: Option<Vec<u8>>,
+   pub len: usize
+}
+
+impl RawString {
+   /// Instantiate a [`RawString`] parser matching the bytes of `to_match`,
+   /// panics if `to_match` is empty
+   pub fn new<S: AsRef<[u8]>>(to_match: S) -> Self {
+      let to_match = to_match.as_ref();
+      if to_match.is_empty() {
+         panic!("RawString: string must not be empty")
+      }
+
+      Self { to_match: Some(to_match.to_vec()), len: to_match.len() }
+   }
+
+   /// Instantiate a [`RawString`] parser returning the next `len` bytes
+   pub fn take(len: usize) -> Self {
+      Self { to_match: None, len }
+   }
+}
+
+impl<T: Input> Parse<u64, u64, Bits<T>> for RawString {
+   fn transform(&self, state: State<u64, Bits<T>>) -> State<u64, Bits<T>> {
+      if state.is_error() {
+         return state;
+      }
+
+      let expected = match &self.to_match {
+         Some(to_match) => format!("'{}'", escape(to_match)),
+         None => format!("{} bytes", self.len)
+      };
+
+      let bytes: Vec<u8> = (0..self.len)
+         .map_while(|i| read_bits(&state.target, state.index + i * 8, 8, Endian::Big))
+         .map(|byte| byte as u8)
+         .collect();
+
+      let mismatch = match &self.to_match {
+         Some(to_match) => !to_match.starts_with(&bytes),
+         None => false
+      };
+
+      if mismatch {
+         let err = ParseError::mismatch("RawString", state.index, expected, escape(&bytes));
+         return state.new_err(err);
+      }
+
+      if bytes.len() < self.len {
+         let needed = self.len - bytes.len();
+         let err = out_of_input(&state.target.bytes, "RawString", state.index, expected, needed);
+         return state.new_err(err);
+      }
+
+      State {
+         index: state.index + self.len * 8,
+         position: state.position,
+         result: Some(Ok(Many(bytes.into_iter().map(u64::from).collect()))),
+         target: state.target,
+         tracker: state.tracker
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use super::{Bits, RawString};
+
+   #[test]
+   fn raw_string_mismatch() {
+      let target = Bits::new(&b"POST"[..]);
+      let err = RawString::new("GET").run(target).result.unwrap().unwrap_err();
+
+      assert!(err.is_mismatch());
+      assert_eq!(err.found.as_deref(), Some("POS"));
+   }
+
+   #[test]
+   fn raw_string_take() {
+      let target = Bits::with_offset(&[0x0f, 0xff, 0xf0][..], 4);
+      let res = RawString::take(2).run(target.clone());
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_many(), vec![0xff, 0xff]);
+      assert!(RawString::take(3).run(target).result.unwrap().unwrap_err().is_end_of_input());
+   }
+}
@@ -0,0 +1,110 @@
+use crate::models::{endian::Endian, input::Input, parser_traits::Parse, state::State};
+use crate::parsers::out_of_input;
+
+use super::{Bits, check_field_size, consume_bits, read_bits};
+
+/// # Uint:
+/// Parse an unsigned integer field of 1 to 64 bits of a [`Bits`] target,
+/// most significant bit first unless built with [`Endian::Little`].
+/// [`Endian::Native`] reads the bits in the byte order of the platform
+///
+/// ### Returns:
+/// The value of the field
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::bit_parsers::{Bits, uint_parser::Uint};
+/// use parser_combinator::models::endian::Endian;
+/// use parser_combinator::collection_parsers::sequence_of_parser::SequenceOf;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// // the version and header length of an IPv4 header
+/// let target = Bits::new(&[0x45u8][..]);
+/// let parser = SequenceOf::new(vec![Box::new(Uint::new(4)), Box::new(Uint::new(4))]);
+/// let result = parser.run(target.clone());
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_many(), vec![4, 5]);
+///
+/// let result = Uint::with_endian(4, Endian::Little).run(target);
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Uint {
+   pub bits: usize,
+   pub endian: Endian
+}
+
+impl Uint {
+   /// Instantiate a big endian [`Uint`] parser of `bits` bits, panics
+   /// unless `bits` is between 1 and 64
+   pub fn new(bits: usize) -> Self {
+      Self::with_endian(bits, Endian::Big)
+   }
+
+   pub fn with_endian(bits: usize, endian: Endian) -> Self {
+      check_field_size("Uint", bits);
+      Self { bits, endian }
+   }
+}
+
+impl<T: Input> Parse<u64, u64, Bits<T>> for Uint {
+   fn transform(&self, state: State<u64, Bits<T>>) -> State<u64, Bits<T>> {
+      if state.is_error() {
+         return state;
+      }
+
+      match read_bits(&state.target, state.index, self.bits, self.endian) {
+         Some(value) => consume_bits(state, self.bits, value),
+         None => {
+            let expected = format!("a {} bit unsigned integer", self.bits);
+            let left = state.target.bit_len().saturating_sub(state.index);
+            let needed = (self.bits - left).div_ceil(8);
+            let err = out_of_input(&state.target.bytes, "Uint", state.index, expected, needed);
+            state.new_err(err)
+         }
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use crate::models::endian::Endian;
+   use super::{Bits, Uint};
+
+   #[test]
+   fn uint_full_width() {
+      let target = Bits::new(&[0xffu8; 8][..]);
+      assert_eq!(Uint::new(64).run(target).result.unwrap().unwrap().unwrap_one(), u64::MAX);
+   }
+
+   #[test]
+   fn uint_across_bytes() {
+      let target = Bits::with_offset(&[0b0000_0111u8, 0b1000_0000][..], 5);
+
+      assert_eq!(Uint::new(4).run(target.clone()).result.unwrap().unwrap().unwrap_one(), 0b1111);
+      assert_eq!(Uint::with_endian(5, Endian::Little).run(target.clone()).result.unwrap().unwrap().unwrap_one(), 0b01111);
+
+      let native = Uint::with_endian(5, Endian::Native).run(target.clone()).result.unwrap().unwrap().unwrap_one();
+      let resolved = Uint::with_endian(5, Endian::Native.resolve()).run(target).result.unwrap().unwrap().unwrap_one();
+      assert_eq!(native, resolved);
+   }
+
+   #[test]
+   fn uint_end_of_input() {
+      let target = Bits::new(&[0xffu8][..]);
+      let err = Uint::new(12).run(target).result.unwrap().unwrap_err();
+
+      assert!(err.is_end_of_input());
+      assert_eq!(err.expected, vec!["a 12 bit unsigned integer"]);
+   }
+
+   #[test]
+   #[should_panic]
+   fn uint_too_wide() {
+      Uint::new(65);
+   }
+}
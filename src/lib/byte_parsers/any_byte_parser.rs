@@ -0,0 +1,60 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::models::{input::{Input, FromSlice}, parser_traits::Parse, state::State};
+use crate::parsers::out_of_input;
+
+use super::consume;
+
+/// # AnyByte:
+/// Parse any single byte of the target, fails only at the end of input
+///
+/// ### Returns:
+/// A one byte [`Vec`] so it can be combined with the other byte parsers
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::any_byte_parser::AnyByte;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target: &[u8] = &[0xca, 0xfe];
+/// let result = AnyByte::new().run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), vec![0xca]);
+/// assert!(AnyByte::new().run(&target[2..]).is_error());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AnyByte<O = Vec<u8>> {
+   _o: PhantomData<O>
+}
+
+impl AnyByte {
+   pub fn new() -> Self {
+      Self { _o: PhantomData }
+   }
+}
+
+impl AnyByte<&[u8]> {
+   /// Instantiate an [`AnyByte`] parser returning a slice of the target
+   pub fn borrowed() -> Self {
+      Self { _o: PhantomData }
+   }
+}
+
+impl<T: Input, O: FromSlice<T> + fmt::Debug> Parse<O, O, T> for AnyByte<O> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      if state.index >= state.target.len() {
+         let err = out_of_input(&*state.target, "AnyByte", state.index, "any byte".to_owned(), 1);
+         return state.new_err(err);
+      }
+
+      consume(state, 1)
+   }
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::models::{input::{Input, FromSlice}, parse_error::ParseError, parser_traits::Parse, state::State};
+use crate::parsers::out_of_input;
+
+use super::{consume, escape};
+
+/// # Byte:
+/// Parse a specific byte in the target
+///
+/// ### Returns:
+/// A one byte [`Vec`] so it can be combined with the other byte parsers
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::byte_parser::Byte;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target: &[u8] = &[0x7f, 0x45, 0x4c, 0x46];
+/// let result = Byte::new(0x7f).run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), vec![0x7f]);
+/// assert_eq!(result.index, 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Byte<O = Vec<u8>> {
+   pub to_match: u8,
+   _o: PhantomData<O>
+}
+
+impl Byte {
+   pub fn new(to_match: u8) -> Self {
+      Self { to_match, _o: PhantomData }
+   }
+}
+
+impl Byte<&[u8]> {
+   /// Instantiate a [`Byte`] parser returning a slice of the target
+   pub fn borrowed(to_match: u8) -> Self {
+      Self { to_match, _o: PhantomData }
+   }
+}
+
+impl<T: Input, O: FromSlice<T> + fmt::Debug> Parse<O, O, T> for Byte<O> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      let expected = format!("'{}'", escape(&[self.to_match]));
+
+      match state.target.byte_at(state.index) {
+         Some(byte) if byte == self.to_match => consume(state, 1),
+         Some(byte) => {
+            let err = ParseError::mismatch("Byte", state.index, expected, escape(&[byte]));
+            state.new_err(err)
+         },
+         None => {
+            let err = out_of_input(&*state.target, "Byte", state.index, expected, 1);
+            state.new_err(err)
+         }
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use super::Byte;
+
+   #[test]
+   fn byte_mismatch() {
+      let target: &[u8] = &[0x00, 0x01];
+      let err = Byte::new(0x01).run(target).result.unwrap().unwrap_err();
+
+      assert!(err.is_mismatch());
+      assert_eq!(err.expected, vec!["'\\x01'"]);
+      assert_eq!(err.found.as_deref(), Some("\\x00"));
+   }
+
+   #[test]
+   fn byte_end_of_input() {
+      let target: &[u8] = &[];
+      let err = Byte::new(0x01).run(target).result.unwrap().unwrap_err();
+
+      assert!(err.is_end_of_input());
+   }
+}
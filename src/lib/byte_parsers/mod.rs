@@ -0,0 +1,186 @@
+
+pub mod byte_parser;
+pub mod any_byte_parser;
+pub mod tag_parser;
+pub mod take_parser;
+pub mod take_while_parser;
+pub mod take_until_parser;
+pub mod number_parser;
+pub mod uleb128_parser;
+pub mod sleb128_parser;
+pub mod zigzag_parser;
+
+use std::rc::Rc;
+
+use crate::models::{cardinality::Cardinality::One, input::{Input, FromSlice}, parse_error::ParseError, state::State};
+use crate::parsers::out_of_input;
+
+/// `bytes` as a printable string for the `expected` and `found` values of a
+/// [`crate::models::parse_error::ParseError`], non printable bytes are
+/// escaped (e.g. `\x00`)
+pub(crate) fn escape(bytes: &[u8]) -> String {
+   bytes.iter()
+      .flat_map(|byte| std::ascii::escape_default(*byte))
+      .map(char::from)
+      .collect()
+}
+
+/// The index of the first occurrence of `tag` in `target` from `index`,
+/// including the ones spanning two chunks of the target
+pub(crate) fn find<T: Input>(target: &T, index: usize, tag: &[u8]) -> Option<usize> {
+   let last = target.len().checked_sub(tag.len())?;
+
+   (index..=last).find(|start| *target.slice(*start, start + tag.len()) == *tag)
+}
+
+/// Consume `len` bytes of the target from the index of `state`, returning
+/// them as the result
+pub(crate) fn consume<T: Input, O: FromSlice<T>>(state: State<O, T>, len: usize) -> State<O, T> {
+   let value = O::from_slice(&*state.target, state.index, state.index + len);
+   consume_value(state, len, value)
+}
+
+/// Consume `len` bytes of the target from the index of `state`, returning
+/// `value` as the result. The position is only advanced on a text target
+pub(crate) fn consume_value<T: Input, R>(state: State<R, T>, len: usize, value: R) -> State<R, T> {
+   let target = Rc::clone(&state.target);
+   let end = state.index + len;
+
+   let position = match target.is_text() {
+      true => state.position.advance(&target.text(state.index, end)),
+      false => state.position
+   };
+
+   State {
+      index: end,
+      position,
+      result: Some(Ok(One(value))),
+      target,
+      tracker: state.tracker
+   }
+}
+
+/// Decode the LEB128 number at `index` of `target`, returning its value and
+/// its length in bytes. A `signed` number is sign extended from its last
+/// byte, the number must fit in `bits` bits either way
+// the error is moved into the state right away, boxing it would only add an allocation
+#[allow(clippy::result_large_err)]
+pub(crate) fn read_leb128<T: Input>(target: &T, parser: &str, index: usize, expected: String, bits: u32, signed: bool) -> Result<(i128, usize), ParseError> {
+   // the most bytes a number of `bits` bits takes, padding included
+   let max_len = bits.div_ceil(7) as usize;
+   let overflow = |len: usize| {
+      let found = escape(&target.slice(index, index + len));
+      ParseError::overflow(parser, index, expected.clone(), found, bits)
+   };
+
+   let mut value: i128 = 0;
+   let mut len = 0;
+   loop {
+      if len == max_len {
+         return Err(overflow(len));
+      }
+
+      let byte = match target.byte_at(index + len) {
+         Some(byte) => byte,
+         None => return Err(out_of_input(target, parser, index, expected, 1))
+      };
+
+      value |= i128::from(byte & 0x7f) << (7 * len);
+      len += 1;
+
+      if byte & 0x80 == 0 {
+         if signed && byte & 0x40 != 0 {
+            value -= 1 << (7 * len);
+         }
+         break;
+      }
+   }
+
+   let (min, max) = if signed {
+      (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+   } else {
+      (0, (1i128 << bits) - 1)
+   };
+
+   if value < min || value > max {
+      return Err(overflow(len));
+   }
+
+   Ok((value, len))
+}
+
+/// Panics unless `bits` is the size of a number that fits in 64 bits
+pub(crate) fn check_number_size(parser: &str, bits: u32) {
+   if !(1..=64).contains(&bits) {
+      panic!("{}: the size of the number must be between 1 and 64 bits, got {}", parser, bits)
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::{
+      choice_parser::Choice,
+      many_parser::Many,
+      sep_by_parser::SepBy,
+      sequence_of_parser::SequenceOf
+   };
+   use crate::models::{parser_traits::Parse, position::Position};
+   use super::{tag_parser::Tag, take_until_parser::TakeUntil, take_while_parser::TakeWhile};
+
+   #[test]
+   fn parses_request_head() {
+      let method = Choice::new(vec![Box::new(Tag::new(b"GET")), Box::new(Tag::new(b"POST"))]);
+      let header = SequenceOf::new(vec![
+         Box::new(TakeUntil::new(b": ")),
+         Box::new(Tag::new(b": ")),
+         Box::new(TakeUntil::new(b"\r\n")),
+         Box::new(Tag::new(b"\r\n"))
+      ]);
+      let head = SequenceOf::new(vec![
+         Box::new(method),
+         Box::new(TakeUntil::new(b"\r\n")),
+         Box::new(Tag::new(b"\r\n")),
+         Box::new(Many::new(header))
+      ]);
+
+      let target: &[u8] = b"POST /upload\r\nHost: example.com\r\nAccept: */*\r\n\r\n";
+      let result = head.run(target);
+      let values = result.result.unwrap().unwrap().unwrap_many();
+
+      assert_eq!(values[0], b"POST");
+      assert_eq!(values[3], b"Host");
+      assert_eq!(values[9], b"*/*");
+      assert_eq!(result.index, target.len() - 2);
+   }
+
+   #[test]
+   fn parses_borrowed_slices() {
+      let parser = SequenceOf::new(vec![
+         Box::new(Tag::borrowed(b"\x7fELF")),
+         Box::new(TakeUntil::borrowed(b"\x00"))
+      ]);
+      let target: &'static [u8] = b"\x7fELF\x02\x01\x00";
+
+      let values: Vec<&[u8]> = parser.run(target).result.unwrap().unwrap().unwrap_many();
+      assert_eq!(values, vec![&target[..4], &target[4..6]]);
+      assert_eq!(values[1].as_ptr(), target[4..].as_ptr());
+   }
+
+   #[test]
+   fn parses_separated_bytes() {
+      let parser = SepBy::new(Tag::new(b","), TakeWhile::new(|byte| byte.is_ascii_digit()));
+      let target: &[u8] = b"10,200,3";
+
+      let values = parser.run(target).result.unwrap().unwrap().unwrap_many();
+      assert_eq!(values, vec![b"10".to_vec(), b"200".to_vec(), b"3".to_vec()]);
+   }
+
+   #[test]
+   fn position_only_tracked_on_text() {
+      let parser = TakeUntil::new(b"=");
+      let bytes: &[u8] = b"a\nb=";
+
+      assert_eq!(parser.run(bytes).position, Position::new());
+      assert_eq!(parser.run("a\nb=").position, Position { line: 2, column: 2 });
+   }
+}
@@ -0,0 +1,167 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::models::{endian::Endian, input::Input, parser_traits::Parse, state::State};
+use crate::parsers::out_of_input;
+
+use super::consume_value;
+
+/// # FromBytes
+/// The number types read by the [`Number`] parser
+pub trait FromBytes: Copy + fmt::Debug {
+   /// The size of the number in bytes
+   const SIZE: usize;
+   /// The name of the type in the errors, e.g. `u16`
+   const NAME: &'static str;
+
+   /// Read the number from exactly [`FromBytes::SIZE`] bytes
+   fn from_bytes(bytes: &[u8], order: Endian) -> Self;
+}
+
+macro_rules! impl_from_bytes {
+   ($($number: ty),*) => {
+      $(
+         impl FromBytes for $number {
+            const SIZE: usize = std::mem::size_of::<$number>();
+            const NAME: &'static str = stringify!($number);
+
+            fn from_bytes(bytes: &[u8], order: Endian) -> Self {
+               let bytes = bytes.try_into().expect("FromBytes: wrong number of bytes");
+               match order {
+                  Endian::Big => <$number>::from_be_bytes(bytes),
+                  Endian::Little => <$number>::from_le_bytes(bytes),
+                  Endian::Native => <$number>::from_ne_bytes(bytes)
+               }
+            }
+         }
+      )*
+   };
+}
+
+impl_from_bytes!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+/// # Number:
+/// Parse a binary number of type `N` stored in `order`, use the aliases
+/// ([`U16`], [`I32`], [`F64`]...) to name the type
+///
+/// ### Returns:
+/// The number as an `N`
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::number_parser::{U16, I32};
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target: &[u8] = &[0x01, 0x02, 0xfe, 0xff, 0xff, 0xff];
+///
+/// assert_eq!(U16::big().run(target).result.unwrap().unwrap().unwrap_one(), 0x0102);
+/// assert_eq!(U16::little().run(target).result.unwrap().unwrap().unwrap_one(), 0x0201);
+/// assert_eq!(I32::little().run(&target[2..]).result.unwrap().unwrap().unwrap_one(), -2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Number<N> {
+   pub order: Endian,
+   _n: PhantomData<N>
+}
+
+impl<N: FromBytes> Number<N> {
+   pub fn new(order: Endian) -> Self {
+      Self { order, _n: PhantomData }
+   }
+
+   /// Instantiate a [`Number`] parser reading the most significant byte first
+   pub fn big() -> Self {
+      Self::new(Endian::Big)
+   }
+
+   /// Instantiate a [`Number`] parser reading the least significant byte first
+   pub fn little() -> Self {
+      Self::new(Endian::Little)
+   }
+
+   /// Instantiate a [`Number`] parser reading the byte order of the target platform
+   pub fn native() -> Self {
+      Self::new(Endian::Native)
+   }
+}
+
+pub type U8 = Number<u8>;
+pub type U16 = Number<u16>;
+pub type U32 = Number<u32>;
+pub type U64 = Number<u64>;
+pub type I8 = Number<i8>;
+pub type I16 = Number<i16>;
+pub type I32 = Number<i32>;
+pub type I64 = Number<i64>;
+pub type F32 = Number<f32>;
+pub type F64 = Number<f64>;
+
+impl<T: Input, N: FromBytes> Parse<N, N, T> for Number<N> {
+   fn transform(&self, state: State<N, T>) -> State<N, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      let target = Rc::clone(&state.target);
+      let left = target.len() - state.index;
+
+      if left < N::SIZE {
+         let expected = match N::SIZE {
+            1 => format!("a {}", N::NAME),
+            _ => format!("a {} endian {}", self.order, N::NAME)
+         };
+         let err = out_of_input(&*target, "Number", state.index, expected, N::SIZE - left);
+         return state.new_err(err);
+      }
+
+      let value = N::from_bytes(&target.slice(state.index, state.index + N::SIZE), self.order);
+      consume_value(state, N::SIZE, value)
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::sequence_of_parser::SequenceOf;
+   use crate::models::parser_traits::Parse;
+   use super::{F32, F64, I8, U32, U64};
+
+   #[test]
+   fn parses_header_fields() {
+      // the little endian size, offset and flags of a header
+      let header = SequenceOf::new(vec![
+         Box::new(U32::little()),
+         Box::new(U32::little()),
+         Box::new(U32::big())
+      ]);
+      let target: &[u8] = &[0x36, 0, 0, 0, 0x0e, 0, 0, 0, 0, 0, 0, 1];
+      let res = header.run(target);
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_many(), vec![54, 14, 1]);
+      assert_eq!(res.index, 12);
+   }
+
+   #[test]
+   fn parses_floats_and_signed() {
+      let target = 1.5f64.to_be_bytes();
+      assert_eq!(F64::big().run(&target[..]).result.unwrap().unwrap().unwrap_one(), 1.5);
+
+      let target = (-0.25f32).to_ne_bytes();
+      assert_eq!(F32::native().run(&target[..]).result.unwrap().unwrap().unwrap_one(), -0.25);
+
+      let target: &[u8] = &[0x80];
+      assert_eq!(I8::big().run(target).result.unwrap().unwrap().unwrap_one(), -128);
+   }
+
+   #[test]
+   fn number_end_of_input() {
+      let target: &[u8] = &[0xff; 5];
+      let err = U64::little().run(target).result.unwrap().unwrap_err();
+
+      assert!(err.is_end_of_input());
+      assert_eq!(err.expected, vec!["a little endian u64"]);
+   }
+}
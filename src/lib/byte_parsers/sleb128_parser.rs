@@ -0,0 +1,99 @@
+use crate::models::{input::Input, parser_traits::Parse, state::State};
+
+use super::{check_number_size, consume_value, read_leb128};
+
+/// # SLeb128:
+/// Parse a signed LEB128 number, encoded like a [`ULeb128`](super::uleb128_parser::ULeb128)
+/// in two's complement and sign extended from the second highest bit of
+/// its last byte. The number must fit in `bits` bits (64 by default), a
+/// larger one fails with an [`ErrorKind::Overflow`](crate::models::parse_error::ErrorKind::Overflow)
+/// error
+///
+/// ### Returns:
+/// The number as an `i64`
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::sleb128_parser::SLeb128;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target: &[u8] = &[0xc0, 0xbb, 0x78];
+/// let result = SLeb128::new().run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), -123456);
+/// assert_eq!(result.index, 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SLeb128 {
+   pub bits: u32
+}
+
+impl SLeb128 {
+   pub fn new() -> Self {
+      Self { bits: 64 }
+   }
+
+   /// Instantiate a [`SLeb128`] parser for numbers of at most `bits` bits,
+   /// panics unless `bits` is between 1 and 64
+   pub fn with_bits(bits: u32) -> Self {
+      check_number_size("SLeb128", bits);
+      Self { bits }
+   }
+}
+
+impl Default for SLeb128 {
+   fn default() -> Self {
+      Self::new()
+   }
+}
+
+impl<T: Input> Parse<i64, i64, T> for SLeb128 {
+   fn transform(&self, state: State<i64, T>) -> State<i64, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      let expected = format!("a {} bit SLEB128 number", self.bits);
+      match read_leb128(&*state.target, "SLeb128", state.index, expected, self.bits, true) {
+         Ok((value, len)) => consume_value(state, len, value as i64),
+         Err(err) => state.new_err(err)
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use super::SLeb128;
+
+   #[test]
+   fn parses_signed_numbers() {
+      let cases: &[(&[u8], i64)] = &[
+         (&[0x02], 2),
+         (&[0x7e], -2),
+         (&[0xff, 0x00], 127),
+         (&[0x81, 0x7f], -127),
+         (&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f], i64::MIN)
+      ];
+
+      for (target, expected) in cases {
+         let res = SLeb128::new().run(*target);
+         assert_eq!(res.result.unwrap().unwrap().unwrap_one(), *expected);
+         assert_eq!(res.index, target.len());
+      }
+   }
+
+   #[test]
+   fn detects_overflow() {
+      // -129 and 128 do not fit in an i8
+      let err = SLeb128::with_bits(8).run(&[0xff, 0x7e][..]).result.unwrap().unwrap_err();
+      assert!(err.is_overflow());
+      assert_eq!(err.found.as_deref(), Some("\\xff~"));
+
+      assert!(SLeb128::with_bits(8).run(&[0x80, 0x01][..]).result.unwrap().unwrap_err().is_overflow());
+      assert_eq!(SLeb128::with_bits(8).run(&[0x80, 0x7f][..]).result.unwrap().unwrap().unwrap_one(), -128);
+   }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::models::{input::{Input, FromSlice}, parse_error::ParseError, parser_traits::Parse, state::State};
+use crate::parsers::{out_of_input, window};
+
+use super::{consume, escape};
+
+/// # Tag:
+/// Parse a specific byte string in the target, the binary counterpart of
+/// [`crate::parsers::str_parser::Str`]
+///
+/// ### Returns:
+/// The matched bytes
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::tag_parser::Tag;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target: &[u8] = b"\x89PNG\r\n\x1a\n";
+/// let result = Tag::new(b"\x89PNG").run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), b"\x89PNG");
+/// assert_eq!(result.index, 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tag<O = Vec<u8>> {
+   pub to_match: Vec<u8>,
+   _o: PhantomData<O>
+}
+
+impl Tag {
+   pub fn new(to_match: &[u8]) -> Self {
+      Self { to_match: to_match.to_vec(), _o: PhantomData }
+   }
+}
+
+impl Tag<&[u8]> {
+   /// Instantiate a [`Tag`] parser returning a slice of the target
+   pub fn borrowed(to_match: &[u8]) -> Self {
+      Self { to_match: to_match.to_vec(), _o: PhantomData }
+   }
+}
+
+impl<T: Input, O: FromSlice<T> + fmt::Debug> Parse<O, O, T> for Tag<O> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      let target = Rc::clone(&state.target);
+      let sliced_target = window(&*target, state.index, self.to_match.len());
+      let expected = format!("'{}'", escape(&self.to_match));
+
+      if *sliced_target == *self.to_match {
+         let len = self.to_match.len();
+         return consume(state, len);
+      }
+
+      if self.to_match.starts_with(&sliced_target) {
+         let needed = self.to_match.len() - sliced_target.len();
+         let err = out_of_input(&*state.target, "Tag", state.index, expected, needed);
+         return state.new_err(err);
+      }
+
+      let found = escape(&sliced_target);
+      let err = ParseError::mismatch("Tag", state.index, expected, found);
+      state.new_err(err)
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use super::Tag;
+
+   #[test]
+   fn tag_mismatch() {
+      let target: &[u8] = b"POST /";
+      let err = Tag::new(b"GET").run(target).result.unwrap().unwrap_err();
+
+      assert!(err.is_mismatch());
+      assert_eq!(err.expected, vec!["'GET'"]);
+      assert_eq!(err.found.as_deref(), Some("POS"));
+   }
+
+   #[test]
+   fn tag_end_of_input() {
+      let target: &[u8] = b"GE";
+      let err = Tag::new(b"GET").run(target).result.unwrap().unwrap_err();
+
+      assert!(err.is_end_of_input());
+   }
+}
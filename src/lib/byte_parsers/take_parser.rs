@@ -0,0 +1,64 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::models::{input::{Input, FromSlice}, parser_traits::Parse, state::State};
+use crate::parsers::out_of_input;
+
+use super::consume;
+
+/// # Take:
+/// Parse exactly `count` bytes of the target, whatever their value
+///
+/// ### Returns:
+/// The `count` bytes, or an end of input error if fewer are left
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::take_parser::Take;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target: &[u8] = &[0x45, 0x00, 0x00, 0x54];
+/// let result = Take::new(2).run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), vec![0x45, 0x00]);
+/// assert!(Take::new(5).run(target).is_error());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Take<O = Vec<u8>> {
+   pub count: usize,
+   _o: PhantomData<O>
+}
+
+impl Take {
+   pub fn new(count: usize) -> Self {
+      Self { count, _o: PhantomData }
+   }
+}
+
+impl Take<&[u8]> {
+   /// Instantiate a [`Take`] parser returning a slice of the target
+   pub fn borrowed(count: usize) -> Self {
+      Self { count, _o: PhantomData }
+   }
+}
+
+impl<T: Input, O: FromSlice<T> + fmt::Debug> Parse<O, O, T> for Take<O> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      let left = state.target.len() - state.index;
+
+      if left < self.count {
+         let expected = format!("{} bytes", self.count);
+         let err = out_of_input(&*state.target, "Take", state.index, expected, self.count - left);
+         return state.new_err(err);
+      }
+
+      consume(state, self.count)
+   }
+}
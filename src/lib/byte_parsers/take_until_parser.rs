@@ -0,0 +1,108 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::models::{input::{Input, FromSlice}, parser_traits::Parse, state::State};
+use crate::parsers::out_of_input;
+
+use super::{consume, escape, find};
+
+/// # TakeUntil:
+/// Parse the bytes of the target up to the next occurrence of `tag`, the
+/// tag itself is not consumed
+///
+/// ### Returns:
+/// The bytes before `tag`, possibly none, or an end of input error if the
+/// tag is not in the rest of the target
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::take_until_parser::TakeUntil;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target: &[u8] = b"GET / HTTP/1.1\r\n";
+/// let result = TakeUntil::new(b"\r\n").run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), b"GET / HTTP/1.1");
+/// assert_eq!(result.index, 14);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TakeUntil<O = Vec<u8>> {
+   pub tag: Vec<u8>,
+   _o: PhantomData<O>
+}
+
+impl<O> TakeUntil<O> {
+   fn with_tag(tag: &[u8]) -> Self {
+      if tag.is_empty() {
+         panic!("TakeUntil: tag must not be empty")
+      }
+
+      Self { tag: tag.to_vec(), _o: PhantomData }
+   }
+}
+
+impl TakeUntil {
+   /// Instantiate a [`TakeUntil`] parser matching up to `tag`, panics if
+   /// `tag` is empty
+   pub fn new(tag: &[u8]) -> Self {
+      Self::with_tag(tag)
+   }
+}
+
+impl TakeUntil<&[u8]> {
+   /// Instantiate a [`TakeUntil`] parser returning a slice of the target,
+   /// panics if `tag` is empty
+   pub fn borrowed(tag: &[u8]) -> Self {
+      Self::with_tag(tag)
+   }
+}
+
+impl<T: Input, O: FromSlice<T> + fmt::Debug> Parse<O, O, T> for TakeUntil<O> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      match find(&*state.target, state.index, &self.tag) {
+         Some(index) => {
+            let len = index - state.index;
+            consume(state, len)
+         },
+         None => {
+            let expected = format!("'{}'", escape(&self.tag));
+            let err = out_of_input(&*state.target, "TakeUntil", state.index, expected, 1);
+            state.new_err(err)
+         }
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use super::TakeUntil;
+
+   #[test]
+   fn take_until_missing_tag() {
+      let target: &[u8] = b"abc";
+      let result = TakeUntil::new(b"\r\n").run(target);
+      let err = result.result.unwrap().unwrap_err();
+
+      assert!(err.is_end_of_input());
+      assert_eq!(err.index, 0);
+      assert_eq!(result.index, 0);
+      assert_eq!(err.expected, vec!["'\\r\\n'"]);
+   }
+
+   #[test]
+   fn take_until_tag_first() {
+      let target: &[u8] = b"\r\n";
+      let result = TakeUntil::new(b"\r\n").run(target);
+
+      assert!(result.result.unwrap().unwrap().unwrap_one().is_empty());
+      assert_eq!(result.index, 0);
+   }
+}
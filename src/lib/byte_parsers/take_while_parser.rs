@@ -0,0 +1,120 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::models::{input::{Input, FromSlice}, parse_error::ParseError, parser_traits::Parse, state::State};
+use crate::parsers::out_of_input;
+
+use super::{consume, escape};
+
+/// # TakeWhile:
+/// Parse at least one byte of the target, continuing while `predicate`
+/// holds for the next byte
+///
+/// ### Returns:
+/// The matched bytes
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::take_while_parser::TakeWhile;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target: &[u8] = b"Host: example.com";
+/// let result = TakeWhile::new(|byte| byte.is_ascii_alphabetic()).run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), b"Host");
+/// assert_eq!(result.index, 4);
+/// ```
+pub struct TakeWhile<O = Vec<u8>> {
+   predicate: Box<dyn Fn(u8) -> bool>,
+   _o: PhantomData<O>
+}
+
+impl TakeWhile {
+   pub fn new<F>(predicate: F) -> Self
+      where F: Fn(u8) -> bool + 'static {
+      Self { predicate: Box::new(predicate), _o: PhantomData }
+   }
+}
+
+impl TakeWhile<&[u8]> {
+   /// Instantiate a [`TakeWhile`] parser returning a slice of the target
+   pub fn borrowed<F>(predicate: F) -> Self
+      where F: Fn(u8) -> bool + 'static {
+      Self { predicate: Box::new(predicate), _o: PhantomData }
+   }
+}
+
+impl<O> fmt::Debug for TakeWhile<O> {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      f.debug_struct("TakeWhile").finish()
+   }
+}
+
+impl<T: Input, O: FromSlice<T> + fmt::Debug> Parse<O, O, T> for TakeWhile<O> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      let expected = "bytes matching the predicate".to_owned();
+
+      // match chunk by chunk until a byte fails the predicate
+      let mut end = state.index;
+      loop {
+         let chunk = state.target.chunk(end);
+         let len = chunk.iter().take_while(|byte| (self.predicate)(**byte)).count();
+
+         end += len;
+         if len < chunk.len() || chunk.is_empty() {
+            break;
+         }
+      }
+
+      match state.target.byte_at(state.index) {
+         None => {
+            let err = out_of_input(&*state.target, "TakeWhile", state.index, expected, 1);
+            state.new_err(err)
+         },
+         // the match could continue in the data that has not arrived yet
+         Some(_) if end == state.target.len() && state.target.is_partial() => {
+            let err = ParseError::incomplete("TakeWhile", state.index, expected, 1);
+            state.new_err(err)
+         },
+         Some(byte) if end == state.index => {
+            let err = ParseError::mismatch("TakeWhile", state.index, expected, escape(&[byte]));
+            state.new_err(err)
+         },
+         Some(_) => {
+            let len = end - state.index;
+            consume(state, len)
+         }
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use super::TakeWhile;
+
+   #[test]
+   fn take_while_needs_one_byte() {
+      let target: &[u8] = &[0x00, 0xff];
+      let parser = TakeWhile::new(|byte| byte == 0xff);
+
+      assert!(parser.run(target).result.unwrap().unwrap_err().is_mismatch());
+      assert!(parser.run(&target[2..]).result.unwrap().unwrap_err().is_end_of_input());
+   }
+
+   #[test]
+   fn take_while_to_end() {
+      let target: &[u8] = &[0xff, 0xff];
+      let result = TakeWhile::new(|byte| byte == 0xff).run(target);
+
+      assert_eq!(result.result.unwrap().unwrap().unwrap_one(), vec![0xff, 0xff]);
+      assert_eq!(result.index, 2);
+   }
+}
@@ -0,0 +1,125 @@
+use crate::models::{input::Input, parser_traits::Parse, state::State};
+
+use super::{check_number_size, consume_value, read_leb128};
+
+/// # ULeb128:
+/// Parse an unsigned LEB128 number, 7 bits per byte starting from the
+/// least significant ones, the high bit of each byte set while more bytes
+/// follow. The number must fit in `bits` bits (64 by default), a larger
+/// one fails with an [`ErrorKind::Overflow`](crate::models::parse_error::ErrorKind::Overflow)
+/// error
+///
+/// ### Returns:
+/// The number as a `u64`
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::uleb128_parser::ULeb128;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target: &[u8] = &[0xe5, 0x8e, 0x26];
+/// let result = ULeb128::new().run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), 624485);
+/// assert_eq!(result.index, 3);
+///
+/// let err = ULeb128::with_bits(8).run(target).result.unwrap().unwrap_err();
+/// assert!(err.is_overflow());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ULeb128 {
+   pub bits: u32
+}
+
+impl ULeb128 {
+   pub fn new() -> Self {
+      Self { bits: 64 }
+   }
+
+   /// Instantiate a [`ULeb128`] parser for numbers of at most `bits` bits,
+   /// panics unless `bits` is between 1 and 64
+   pub fn with_bits(bits: u32) -> Self {
+      check_number_size("ULeb128", bits);
+      Self { bits }
+   }
+}
+
+impl Default for ULeb128 {
+   fn default() -> Self {
+      Self::new()
+   }
+}
+
+/// # Varint:
+/// The variable length integers of Protocol Buffers, encoded as an
+/// unsigned LEB128 number
+pub type Varint = ULeb128;
+
+impl<T: Input> Parse<u64, u64, T> for ULeb128 {
+   fn transform(&self, state: State<u64, T>) -> State<u64, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      let expected = format!("a {} bit ULEB128 number", self.bits);
+      match read_leb128(&*state.target, "ULeb128", state.index, expected, self.bits, false) {
+         Ok((value, len)) => consume_value(state, len, value as u64),
+         Err(err) => state.new_err(err)
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::sequence_of_parser::SequenceOf;
+   use crate::models::parser_traits::Parse;
+   use super::{ULeb128, Varint};
+
+   #[test]
+   fn parses_varints() {
+      // a single byte, the largest u64, and a padded zero
+      let target: &[u8] = &[
+         0x7f,
+         0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01,
+         0x80, 0x00
+      ];
+      let parser = SequenceOf::new(vec![
+         Box::new(Varint::new()),
+         Box::new(Varint::new()),
+         Box::new(Varint::new())
+      ]);
+      let res = parser.run(target);
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_many(), vec![127, u64::MAX, 0]);
+      assert_eq!(res.index, 13);
+   }
+
+   #[test]
+   fn detects_overflow() {
+      // 2^64 does not fit in a u64
+      let target: &[u8] = &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02];
+      let err = ULeb128::new().run(target).result.unwrap().unwrap_err();
+      assert!(err.is_overflow());
+      assert_eq!(err.index, 0);
+
+      // an eleventh byte is never needed
+      let target: &[u8] = &[0x80; 11];
+      assert!(ULeb128::new().run(target).result.unwrap().unwrap_err().is_overflow());
+
+      let target: &[u8] = &[0x80, 0x02];
+      assert!(ULeb128::with_bits(8).run(target).result.unwrap().unwrap_err().is_overflow());
+      assert_eq!(ULeb128::with_bits(9).run(target).result.unwrap().unwrap().unwrap_one(), 256);
+   }
+
+   #[test]
+   fn needs_the_last_byte() {
+      let target: &[u8] = &[0xe5, 0x8e];
+      let err = ULeb128::new().run(target).result.unwrap().unwrap_err();
+
+      assert!(err.is_end_of_input());
+      assert_eq!(err.expected, vec!["a 64 bit ULEB128 number"]);
+   }
+}
@@ -0,0 +1,96 @@
+use crate::models::{input::Input, parser_traits::Parse, state::State};
+
+use super::{check_number_size, consume_value, read_leb128};
+
+/// # ZigZag:
+/// Parse a signed number zigzag encoded in a [`Varint`](super::uleb128_parser::Varint),
+/// the `sint32` and `sint64` fields of Protocol Buffers: 0, -1, 1, -2...
+/// are encoded as 0, 1, 2, 3... The encoded number must fit in `bits` bits
+/// (64 by default), a larger one fails with an
+/// [`ErrorKind::Overflow`](crate::models::parse_error::ErrorKind::Overflow) error
+///
+/// ### Returns:
+/// The decoded number as an `i64`
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::zigzag_parser::ZigZag;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target: &[u8] = &[0x03];
+/// assert_eq!(ZigZag::new().run(target).result.unwrap().unwrap().unwrap_one(), -2);
+///
+/// let target: &[u8] = &[0xac, 0x02];
+/// assert_eq!(ZigZag::new().run(target).result.unwrap().unwrap().unwrap_one(), 150);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ZigZag {
+   pub bits: u32
+}
+
+impl ZigZag {
+   pub fn new() -> Self {
+      Self { bits: 64 }
+   }
+
+   /// Instantiate a [`ZigZag`] parser for numbers of at most `bits` bits,
+   /// e.g. 32 for an `sint32`, panics unless `bits` is between 1 and 64
+   pub fn with_bits(bits: u32) -> Self {
+      check_number_size("ZigZag", bits);
+      Self { bits }
+   }
+}
+
+impl Default for ZigZag {
+   fn default() -> Self {
+      Self::new()
+   }
+}
+
+impl<T: Input> Parse<i64, i64, T> for ZigZag {
+   fn transform(&self, state: State<i64, T>) -> State<i64, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      let expected = format!("a {} bit zigzag varint", self.bits);
+      match read_leb128(&*state.target, "ZigZag", state.index, expected, self.bits, false) {
+         Ok((value, len)) => {
+            let value = value as u64;
+            consume_value(state, len, (value >> 1) as i64 ^ -((value & 1) as i64))
+         },
+         Err(err) => state.new_err(err)
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::many_parser::Many;
+   use crate::models::parser_traits::Parse;
+   use super::ZigZag;
+
+   #[test]
+   fn decodes_zigzag() {
+      let target: &[u8] = &[0x00, 0x01, 0x02, 0x03, 0xfe, 0xff, 0xff, 0xff, 0x0f, 0xff, 0xff, 0xff, 0xff, 0x0f];
+      let res = Many::new(ZigZag::with_bits(32)).run(target);
+
+      assert_eq!(
+         res.result.unwrap().unwrap().unwrap_many(),
+         vec![0, -1, 1, -2, i32::MAX as i64, i32::MIN as i64]
+      );
+      assert_eq!(res.index, 14);
+   }
+
+   #[test]
+   fn extremes_of_i64() {
+      let max: &[u8] = &[0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+      let min: &[u8] = &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+
+      assert_eq!(ZigZag::new().run(max).result.unwrap().unwrap().unwrap_one(), i64::MAX);
+      assert_eq!(ZigZag::new().run(min).result.unwrap().unwrap().unwrap_one(), i64::MIN);
+   }
+}
@@ -0,0 +1,208 @@
+use std::rc::Rc;
+
+use crate::models::input::{Input, str_unit_len};
+
+/// # ChunkedText
+/// A text target stored as a list of chunks instead of a contiguous
+/// string, e.g. the chunks of the rope of an editor buffer. The parsers
+/// match across the chunk boundaries as if the text was contiguous, the
+/// chunks are only copied when a match spans several of them.
+///
+/// The chunks are shared, building a new target after an edit only clones
+/// the chunks that did not change. With the `ropey` feature a
+/// [`ropey::Rope`] is a target as well
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::chunked_text::ChunkedText;
+/// use parser_combinator::models::parser_traits::Parse;
+/// use parser_combinator::parsers::letters_parser::Letters;
+///
+/// let text: ChunkedText = vec!["let na", "me = 1"].into_iter().collect();
+/// let result = Letters::new().run(text);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), "let");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChunkedText {
+   chunks: Vec<Rc<str>>,
+   /// The index in the text of the first byte of each chunk
+   starts: Vec<usize>,
+   len: usize
+}
+
+impl ChunkedText {
+   pub fn new() -> Self {
+      Self::default()
+   }
+
+   /// Append `chunk` to the end of the text
+   pub fn push<C: Into<Rc<str>>>(&mut self, chunk: C) {
+      let chunk = chunk.into();
+
+      // an empty chunk would hold no index
+      if chunk.is_empty() {
+         return;
+      }
+
+      self.starts.push(self.len);
+      self.len += chunk.len();
+      self.chunks.push(chunk);
+   }
+
+   pub fn chunks(&self) -> impl Iterator<Item = &Rc<str>> {
+      self.chunks.iter()
+   }
+
+   /// The chunk holding `index` and the offset of `index` in it, `None` at
+   /// the end of the text
+   fn locate(&self, index: usize) -> Option<(&str, usize)> {
+      if index >= self.len {
+         return None;
+      }
+
+      let chunk = match self.starts.binary_search(&index) {
+         Ok(chunk) => chunk,
+         Err(next) => next - 1
+      };
+
+      Some((&self.chunks[chunk], index - self.starts[chunk]))
+   }
+}
+
+impl<C: Into<Rc<str>>> FromIterator<C> for ChunkedText {
+   fn from_iter<I: IntoIterator<Item = C>>(chunks: I) -> Self {
+      let mut text = Self::new();
+      for chunk in chunks {
+         text.push(chunk);
+      }
+
+      text
+   }
+}
+
+impl Input for ChunkedText {
+   fn len(&self) -> usize { self.len }
+
+   fn chunk(&self, index: usize) -> &[u8] {
+      self.locate(index)
+         .map(|(chunk, offset)| &chunk.as_bytes()[offset..])
+         .unwrap_or_default()
+   }
+
+   // the chunks are strings, a char is never split between two of them
+   fn unit_len(&self, index: usize) -> usize {
+      self.locate(index)
+         .map(|(chunk, offset)| str_unit_len(chunk, offset))
+         .unwrap_or(0)
+   }
+
+   fn is_boundary(&self, index: usize) -> bool {
+      match self.locate(index) {
+         Some((chunk, offset)) => chunk.is_char_boundary(offset),
+         None => index == self.len
+      }
+   }
+
+   fn is_text(&self) -> bool { true }
+}
+
+#[cfg(feature = "ropey")]
+impl Input for ropey::Rope {
+   fn len(&self) -> usize { self.len_bytes() }
+
+   fn chunk(&self, index: usize) -> &[u8] {
+      let (chunk, start, _, _) = self.chunk_at_byte(index);
+      &chunk.as_bytes()[index - start..]
+   }
+
+   fn unit_len(&self, index: usize) -> usize {
+      let (chunk, start, _, _) = self.chunk_at_byte(index);
+      str_unit_len(chunk, index - start)
+   }
+
+   fn is_boundary(&self, index: usize) -> bool {
+      if index > self.len_bytes() {
+         return false;
+      }
+
+      let (chunk, start, _, _) = self.chunk_at_byte(index);
+      chunk.is_char_boundary(index - start)
+   }
+
+   fn is_text(&self) -> bool { true }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::{sep_by_parser::SepBy, sequence_of_parser::SequenceOf};
+   use crate::byte_parsers::take_until_parser::TakeUntil;
+   use crate::models::{input::Input, parser_traits::Parse};
+   use crate::parsers::{digits_parser::Digits, letters_parser::Letters, str_parser::Str};
+   use super::ChunkedText;
+
+   fn chunked(chunks: &[&str]) -> ChunkedText {
+      chunks.iter().copied().collect()
+   }
+
+   #[test]
+   fn locates_chunks() {
+      let text = chunked(&["ab", "", "cñ", "d"]);
+
+      assert_eq!(text.len(), 6);
+      assert_eq!(text.chunk(1), b"b");
+      assert_eq!(text.chunk(2), "cñ".as_bytes());
+      assert_eq!(text.chunk(6), b"");
+      assert_eq!(text.slice(1, 5), "bcñ".as_bytes());
+      assert_eq!(text.text(3, 6), "ñd");
+      assert_eq!(text.unit_len(3), 2);
+      assert!(!text.is_boundary(4));
+   }
+
+   #[test]
+   fn parsers_match_across_chunks() {
+      let parser = SequenceOf::new(vec![
+         Box::new(Letters::new()),
+         Box::new(Str::new(" = ".to_owned())),
+         Box::new(Digits::new()),
+         Box::new(Str::new(";".to_owned()))
+      ]);
+      let res = parser.run(chunked(&["co", "unt =", " 1", "2", ";"]));
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_many(), vec!["count", " = ", "12", ";"]);
+      assert_eq!(res.index, 11);
+   }
+
+   #[test]
+   fn errors_across_chunks() {
+      let parser = SepBy::new(Str::new(", ".to_owned()), Digits::new());
+      let res = parser.run(chunked(&["1,", " 2,", "x"]));
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_many(), vec!["1", "2"]);
+      assert_eq!(res.index, 4);
+
+      let err = Str::new("lambda".to_owned()).run(chunked(&["lam", "da x"])).result.unwrap().unwrap_err();
+      assert_eq!(err.found.as_deref(), Some("lamda "));
+      assert_eq!(err.suggestions(), vec!["lambda"]);
+   }
+
+   #[test]
+   fn take_until_tag_across_chunks() {
+      let res = TakeUntil::new(b"\r\n").run(chunked(&["GET /\r", "\nHost"]));
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_one(), b"GET /");
+      assert_eq!(res.index, 5);
+   }
+
+   #[cfg(feature = "ropey")]
+   #[test]
+   fn parses_ropes() {
+      let rope = ropey::Rope::from_str(&"word ".repeat(1000));
+      let parser = SepBy::new(Str::new(" ".to_owned()), Letters::new());
+      let res = parser.run(rope);
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_many().len(), 1000);
+      assert_eq!(res.index, 4999);
+   }
+}
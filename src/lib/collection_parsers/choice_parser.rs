@@ -1,12 +1,18 @@
 use std::{rc::Rc, fmt};
 
-use crate::models::{parser_traits::Parse, state::State};
+use crate::models::{parser_traits::{Parse, CombinatorError}, state::State};
+use crate::models::parse_error::{ParseError, ErrorKind};
 
 /// # Choice
 /// Goes the through the provided parsers and completes as soon as
 /// one of them parses successfully. The Choice parser will ignore failed
 /// parses but will return an error if none of the choices have executed successfully
 /// 
+/// The error merges the expected values of every choice that failed at the
+/// furthest index, e.g. "expected one of '+', '-' but found 'x'". A fatal
+/// error (see [`crate::parsers::cut_parser::Cut`]) or an incomplete one
+/// (see [`crate::streaming`]) is returned straight away
+/// 
 /// ### Returns: 
 /// The state of the first successful parser passed in
 /// 
@@ -30,24 +36,24 @@ use crate::models::{parser_traits::Parse, state::State};
 /// assert_eq!(res.index, 5);
 /// ```
 #[derive(Debug)]
-pub struct Choice<R1,R2,T> {
-   parsers: Vec<Box<dyn Parse<R1,R2,T>>>
+pub struct Choice<R1,R2,T,E = ParseError> {
+   parsers: Vec<Box<dyn Parse<R1,R2,T,E>>>
 }
 
-impl<R1,R2,T> Choice<R1,R2,T> {
-   pub fn new(parsers: Vec<Box<dyn Parse<R1,R2,T>>>) -> Self {
+impl<R1,R2,T,E> Choice<R1,R2,T,E> {
+   pub fn new(parsers: Vec<Box<dyn Parse<R1,R2,T,E>>>) -> Self {
       Self { parsers }
    }
 
-   pub fn push_parser(&mut self, parser: Box<dyn Parse<R1,R2,T>>) {
+   pub fn push_parser(&mut self, parser: Box<dyn Parse<R1,R2,T,E>>) {
       self.parsers.push(parser);
    }
 }
 
-impl<R1,R2,T> Parse<R1,R2,T> for Choice<R1,R2,T> 
-   where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug {
+impl<R1,R2,T,E> Parse<R1,R2,T,E> for Choice<R1,R2,T,E> 
+   where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug, E: CombinatorError {
 
-   fn transform(&self, state: State<R1, T>) -> State<R2, T> {
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
 
       let contains_error = state.is_error();
 
@@ -56,40 +62,58 @@ impl<R1,R2,T> Parse<R1,R2,T> for Choice<R1,R2,T>
          return State::from_err_state(state)
       }
 
-      let mut final_state: State<R1, T> = State {
-         index: state.index,
-         target: Rc::clone(&state.target),
-         result: None
-      };
+      let mut final_state: State<R1, T, E> = state.reset();
 
+      let mut errors = Vec::with_capacity(self.parsers.len());
       for parser in &self.parsers {
          let next = parser.transform(final_state);
 
-         match next.result.as_ref().unwrap() {
-            Ok(_) => return next,
-            _ => {
-               final_state = State {
-                  index: state.index,
-                  target: Rc::clone(&state.target),
-                  result: None
-               };               
-            }
+         match next.result {
+            Some(Ok(_)) => return next,
+            // the choice committed to this parser, don't try the others
+            Some(Err(ref err)) if err.is_fatal() || err.is_incomplete() => return next,
+            Some(Err(err)) => {
+               errors.push(err);
+               final_state = state.reset();               
+            },
+            None => panic!("Choice: parser returned an empty result")
          }
 
       }
 
-      let res = State {
+      // a user defined error is more specific than a mismatch, report it as is
+      let err = match errors.iter().position(|err| err.as_parse_error().is_none()) {
+         Some(custom) => errors.swap_remove(custom),
+         None => {
+            let parse_errors = errors
+               .iter()
+               .filter_map(|err| err.as_parse_error().cloned())
+               .collect();
+
+            let err = ParseError::merge("Choice", parse_errors)
+               .unwrap_or_else(|| {
+                  let mut err = ParseError::new(ErrorKind::Mismatch, "Choice", state.index);
+                  err.expected.push("one of the provided choices".to_owned());
+                  err
+               });
+
+            E::from(err)
+         }
+      };
+
+      State {
          index: state.index,
          target: Rc::clone(&state.target),
-         result: Some(Err("Choice: Failed to parse any of the provided choices".to_owned()))
-      };
-      
-      res
+         position: state.position,
+         result: Some(Err(err)),
+         tracker: Rc::clone(&state.tracker)
+      }
    }
 }
 
 #[cfg(test)]
 mod tests {
+   use crate::collection_parsers::sequence_of_parser::SequenceOf;
    use crate::parsers::str_parser::Str;
    use super::*;
 
@@ -131,4 +155,50 @@ mod tests {
       assert_eq!(res.result.unwrap().unwrap().unwrap_one(), "Test2");
       assert_eq!(res.index, 5);
    }
+
+   #[test]
+   fn fail_none_match() {
+      let s1 = Box::new(Str::new("Test1".to_owned()));  
+      let s2 = Box::new(Str::new("Test2".to_owned()));  
+
+      let choice = Choice::new(vec![s1,s2]);
+      let res = choice.run("Stuff");
+
+      let err = res.result.unwrap().unwrap_err();
+      assert!(err.is_mismatch());
+      assert_eq!(err.parser, "Choice");
+      assert_eq!(err.expected, vec!["'Test1'", "'Test2'"]);
+      assert_eq!(err.found.as_deref(), Some("Stuff"));
+      assert_eq!(res.index, 0);
+   }
+
+   #[test]
+   fn fail_reports_furthest_choice() {
+      let s1 = Box::new(Str::new("+".to_owned()));
+      let s2 = Box::new(Str::new("-".to_owned()));
+      let nested = Box::new(SequenceOf::new(vec![
+         Box::new(Str::new("(".to_owned())),
+         Box::new(Str::new("x".to_owned()))
+      ]));
+
+      let choice = Choice::new(vec![s1, nested, s2]);
+      let err = choice.run("(y").result.unwrap().unwrap_err();
+
+      assert_eq!(err.index, 1);
+      assert_eq!(err.expected, vec!["'x'"]);
+   }
+
+   #[test]
+   fn fail_suggests_closest_keyword() {
+      let keywords = ["define", "lambda", "let", "if"]
+         .iter()
+         .map(|keyword| Box::new(Str::new(keyword.to_string())) as Box<dyn Parse<String, String, &str>>)
+         .collect();
+
+      let choice = Choice::new(keywords);
+      let err = choice.run("lamda (x) x").result.unwrap().unwrap_err();
+
+      assert_eq!(err.expected.len(), 4);
+      assert_eq!(err.suggestions(), vec!["lambda"]);
+   }
 }
@@ -0,0 +1,162 @@
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+use crate::models::cardinality::Cardinality;
+use crate::models::parse_error::ParseError;
+use crate::models::parser_traits::{Parse, CombinatorError};
+use crate::models::state::State;
+
+use super::read_prefix;
+
+/// # CountPrefixed:
+/// Parse a count with `count_parser`, then run `parser` exactly that many
+/// times, e.g. the entries of a table preceded by their number. Fails if
+/// any of the items fails, or matches without consuming input: the count
+/// comes from the input and could otherwise repeat it up to `u64::MAX` times
+///
+/// ### Returns:
+/// A result of type [`Cardinality::Many`] with the results of the items
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::{number_parser::U16, uleb128_parser::Varint};
+/// use parser_combinator::collection_parsers::count_prefixed_parser::CountPrefixed;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// // three big endian u16s followed by an unrelated byte
+/// let target: &[u8] = &[0x03, 0x00, 0x01, 0x00, 0x02, 0x01, 0x00, 0xff];
+/// let result = CountPrefixed::new(Varint::new(), U16::big()).run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_many(), vec![1, 2, 256]);
+/// assert_eq!(result.index, 7);
+/// ```
+pub struct CountPrefixed<C, I, C1, N, R1, R2, T, E = ParseError>
+   where C: Parse<C1, N, T, E>, I: Parse<R1, R2, T, E> {
+   count_parser: C,
+   parser: I,
+   _p1: PhantomData<C1>,
+   _p2: PhantomData<N>,
+   _p3: PhantomData<R1>,
+   _p4: PhantomData<R2>,
+   _p5: PhantomData<T>,
+   _p6: PhantomData<E>,
+}
+
+impl<C, I, C1, N, R1, R2, T, E> CountPrefixed<C, I, C1, N, R1, R2, T, E>
+   where C: Parse<C1, N, T, E>, I: Parse<R1, R2, T, E> {
+
+   pub fn new(count_parser: C, parser: I) -> Self {
+      Self {
+         count_parser,
+         parser,
+         _p1: PhantomData,
+         _p2: PhantomData,
+         _p3: PhantomData,
+         _p4: PhantomData,
+         _p5: PhantomData,
+         _p6: PhantomData
+      }
+   }
+}
+
+impl<C, I, C1, N, R1, R2, T, E> Debug for CountPrefixed<C, I, C1, N, R1, R2, T, E>
+   where C: Parse<C1, N, T, E>, I: Parse<R1, R2, T, E> {
+
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      f.debug_struct("CountPrefixed")
+         .field("count_parser", &self.count_parser)
+         .field("parser", &self.parser)
+         .finish()
+   }
+}
+
+impl<C, I, C1, N, R1, R2, T, E> Parse<R1, R2, T, E> for CountPrefixed<C, I, C1, N, R1, R2, T, E>
+   where C1: Debug, N: TryInto<usize> + Copy + Debug, R1: Debug, R2: Debug, T: Debug,
+      E: CombinatorError, C: Parse<C1, N, T, E>, I: Parse<R1, R2, T, E> {
+
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
+      if state.is_error() {
+         return State::from_err_state(state);
+      }
+
+      let (count, mut state) = match read_prefix(&self.count_parser, "CountPrefixed", state) {
+         Ok(prefix) => prefix,
+         Err(state) => return state
+      };
+
+      // the count is read from the input, it does not size the results
+      let mut results: Vec<R2> = Vec::new();
+      for item in 0..count {
+         let start = state.index;
+         let next = self.parser.transform(state);
+         state = next.reset();
+
+         match next.result.unwrap() {
+            Ok(_) if state.index == start => {
+               let message = format!("item {} of {} consumed no input", item + 1, count);
+               let err = ParseError::custom("CountPrefixed", start, message);
+               return state.new_err(E::from(err));
+            },
+            Ok(Cardinality::One(res)) => results.push(res),
+            Ok(Cardinality::Many(mut res)) => results.append(&mut res),
+            Err(err) => return state.forward_err(err)
+         }
+      }
+
+      State {
+         index: state.index,
+         target: state.target,
+         position: state.position,
+         result: Some(Ok(Cardinality::Many(results))),
+         tracker: state.tracker
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::byte_parsers::{number_parser::{I8, U8}, tag_parser::Tag, take_parser::Take, uleb128_parser::Varint};
+   use crate::collection_parsers::{length_prefixed_parser::LengthPrefixed, many_parser::Many};
+   use crate::models::parser_traits::Parse;
+   use super::CountPrefixed;
+
+   #[test]
+   fn parses_prefixed_strings() {
+      // two Pascal strings and a trailing tag
+      let strings = CountPrefixed::new(U8::big(), LengthPrefixed::new(U8::big(), Take::new(3)));
+      let res = strings.run(&b"\x02\x03abc\x03defEND"[..]);
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_many(), vec![b"abc".to_vec(), b"def".to_vec()]);
+      assert_eq!(res.index, 9);
+   }
+
+   #[test]
+   fn zero_items() {
+      let res = CountPrefixed::new(U8::big(), Tag::new(b"x")).run(&b"\x00x"[..]);
+
+      assert!(res.result.unwrap().unwrap().unwrap_many().is_empty());
+      assert_eq!(res.index, 1);
+   }
+
+   #[test]
+   fn missing_items_fail() {
+      let err = CountPrefixed::new(U8::big(), Tag::new(b"x")).run(&b"\x03xx"[..]).result.unwrap().unwrap_err();
+      assert!(err.is_end_of_input());
+      assert_eq!(err.index, 3);
+
+      let err = CountPrefixed::new(I8::big(), Tag::new(b"x")).run(&b"\xffx"[..]).result.unwrap().unwrap_err();
+      assert_eq!(err.to_string(), "CountPrefixed: invalid size -1 at index 0");
+   }
+
+   #[test]
+   fn zero_width_items_fail() {
+      // a count of u64::MAX items that match nothing
+      let target: &[u8] = &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, 0x00];
+      let err = CountPrefixed::new(Varint::new(), Many::new(Tag::new(b"x"))).run(target).result.unwrap().unwrap_err();
+
+      assert_eq!(err.to_string(), format!("CountPrefixed: item 1 of {} consumed no input at index 10", u64::MAX));
+   }
+}
@@ -0,0 +1,208 @@
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::byte_parsers::escape;
+use crate::models::input::{Input, FromSlice};
+use crate::models::parse_error::ParseError;
+use crate::models::parser_traits::{Parse, CombinatorError};
+use crate::models::state::State;
+use crate::models::tracker::Tracker;
+use crate::parsers::out_of_input;
+
+use super::{read_prefix, shift_error};
+
+/// # LengthPrefixed:
+/// Parse a length with `len_parser`, then run `parser` on exactly that many
+/// bytes, e.g. the value of a TLV record or a Pascal string. The body parser
+/// sees a target ending after the length, it cannot read past it and must
+/// consume all of it.
+///
+/// The length is any result converting to a `usize` (the number parsers,
+/// [`crate::byte_parsers::uleb128_parser::Varint`]...), the target any
+/// input slicing into its own type: `&[u8]`, `&str`, `String`, `Vec<u8>`
+/// or a [`crate::streaming::Partial`] of one
+///
+/// ### Returns:
+/// The result of `parser`
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::byte_parsers::{number_parser::U8, take_while_parser::TakeWhile};
+/// use parser_combinator::collection_parsers::length_prefixed_parser::LengthPrefixed;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// // a 5 byte name followed by more letters
+/// let target: &[u8] = b"\x05helloworld";
+/// let name = LengthPrefixed::new(U8::big(), TakeWhile::new(|byte| byte.is_ascii_alphabetic()));
+/// let result = name.run(target);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), b"hello");
+/// assert_eq!(result.index, 6);
+/// ```
+pub struct LengthPrefixed<L, I, L1, N, R1, R2, T, E = ParseError>
+   where L: Parse<L1, N, T, E>, I: Parse<R1, R2, T, E> {
+   len_parser: L,
+   parser: I,
+   _p1: PhantomData<L1>,
+   _p2: PhantomData<N>,
+   _p3: PhantomData<R1>,
+   _p4: PhantomData<R2>,
+   _p5: PhantomData<T>,
+   _p6: PhantomData<E>,
+}
+
+impl<L, I, L1, N, R1, R2, T, E> LengthPrefixed<L, I, L1, N, R1, R2, T, E>
+   where L: Parse<L1, N, T, E>, I: Parse<R1, R2, T, E> {
+
+   pub fn new(len_parser: L, parser: I) -> Self {
+      Self {
+         len_parser,
+         parser,
+         _p1: PhantomData,
+         _p2: PhantomData,
+         _p3: PhantomData,
+         _p4: PhantomData,
+         _p5: PhantomData,
+         _p6: PhantomData
+      }
+   }
+}
+
+impl<L, I, L1, N, R1, R2, T, E> Debug for LengthPrefixed<L, I, L1, N, R1, R2, T, E>
+   where L: Parse<L1, N, T, E>, I: Parse<R1, R2, T, E> {
+
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      f.debug_struct("LengthPrefixed")
+         .field("len_parser", &self.len_parser)
+         .field("parser", &self.parser)
+         .finish()
+   }
+}
+
+impl<L, I, L1, N, R1, R2, T, E> Parse<R1, R2, T, E> for LengthPrefixed<L, I, L1, N, R1, R2, T, E>
+   where L1: Debug, N: TryInto<usize> + Copy + Debug, R1: Debug, R2: Debug,
+      T: Input + FromSlice<T> + Debug, E: CombinatorError,
+      L: Parse<L1, N, T, E>, I: Parse<R1, R2, T, E> {
+
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
+      if state.is_error() {
+         return State::from_err_state(state);
+      }
+
+      let (len, state) = match read_prefix(&self.len_parser, "LengthPrefixed", state) {
+         Ok(prefix) => prefix,
+         Err(state) => return state
+      };
+
+      let target = Rc::clone(&state.target);
+      let start = state.index;
+      let left = target.len() - start;
+
+      if len > left {
+         let expected = format!("a body of {} bytes", len);
+         let err = out_of_input(&*target, "LengthPrefixed", start, expected, len - left);
+         return state.new_err(E::from(err));
+      }
+
+      let end = start + len;
+      if !target.is_boundary(end) {
+         let message = format!("a body of {} bytes ends inside a char", len);
+         return state.new_err(E::from(ParseError::custom("LengthPrefixed", start, message)));
+      }
+
+      // the body runs on its own target, its failures are moved to the
+      // run's tracker at their index in the whole target once it is done
+      let body_tracker = Rc::new(Tracker::new());
+      let body = self.parser.transform(State {
+         index: 0,
+         target: Rc::new(T::from_slice(&*target, start, end)),
+         position: state.position,
+         result: None,
+         tracker: Rc::clone(&body_tracker)
+      });
+
+      if let Some(failure) = body_tracker.farthest() {
+         state.tracker.record(failure.position, &shift_error(failure.error, start));
+      }
+
+      for err in body_tracker.errors() {
+         state.tracker.push_error(shift_error(err, start));
+      }
+
+      let after = State {
+         index: start + body.index,
+         target,
+         position: body.position,
+         result: None,
+         tracker: state.tracker
+      };
+
+      match body.result.unwrap() {
+         Err(err) => after.forward_err(shift_error(err, start)),
+         Ok(_) if after.index < end => {
+            let expected = format!("the end of the {} byte body", len);
+            let found = escape(&after.target.slice(after.index, end));
+            let err = ParseError::mismatch("LengthPrefixed", after.index, expected, found);
+            after.new_err(E::from(err))
+         },
+         Ok(result) => State { index: end, result: Some(Ok(result)), ..after }
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::byte_parsers::{number_parser::{U8, U16}, tag_parser::Tag, take_while_parser::TakeWhile};
+   use crate::collection_parsers::sep_by_parser::SepBy;
+   use crate::models::parser_traits::Parse;
+   use crate::streaming::Partial;
+   use super::LengthPrefixed;
+
+   #[test]
+   fn body_is_limited_to_the_length() {
+      let words = SepBy::new(Tag::new(b","), TakeWhile::new(|byte| byte.is_ascii_alphabetic()));
+      let parser = LengthPrefixed::new(U16::big(), words);
+      let res = parser.run(&b"\x00\x0aalpha,betagamma"[..]);
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_many(), vec![b"alpha".to_vec(), b"beta".to_vec()]);
+      assert_eq!(res.index, 12);
+   }
+
+   #[test]
+   fn body_must_consume_the_length() {
+      let parser = LengthPrefixed::new(U8::big(), TakeWhile::new(|byte| byte.is_ascii_alphabetic()));
+      let err = parser.run(&b"\x05hi!!!"[..]).result.unwrap().unwrap_err();
+
+      assert!(err.is_mismatch());
+      assert_eq!(err.index, 3);
+      assert_eq!(err.found.as_deref(), Some("!!!"));
+   }
+
+   #[test]
+   fn body_errors_index_the_whole_target() {
+      let parser = LengthPrefixed::new(U8::big(), Tag::new(b"ab"));
+      let err = parser.run(&b"\x02a!"[..]).result.unwrap().unwrap_err();
+
+      assert_eq!(err.parser, "Tag");
+      assert_eq!(err.index, 1);
+   }
+
+   #[test]
+   fn length_past_the_end() {
+      let parser = LengthPrefixed::new(U8::big(), Tag::new(b"abcd"));
+      let err = parser.run(&b"\x04ab"[..]).result.unwrap().unwrap_err();
+      assert!(err.is_end_of_input());
+
+      // a streamed record waits for the rest of its body
+      let parser = LengthPrefixed::new(U8::big(), Tag::new(b"abcd"));
+      let err = parser.run(Partial::new(&b"\x04ab"[..], false)).result.unwrap().unwrap_err();
+      assert!(err.is_incomplete());
+
+      let res = parser.run(Partial::new(&b"\x04abcd"[..], false));
+      assert_eq!(res.result.unwrap().unwrap().unwrap_one(), b"abcd");
+   }
+}
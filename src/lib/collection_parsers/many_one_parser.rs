@@ -1,5 +1,6 @@
 use crate::models::cardinality::Cardinality;
-use crate::models::parser_traits::Parse;
+use crate::models::parse_error::{ParseError, ErrorKind};
+use crate::models::parser_traits::{Parse, CombinatorError};
 use crate::models::state::State;
 use std::fmt;
 use std::marker::PhantomData;
@@ -32,68 +33,78 @@ use std::rc::Rc;
 /// assert_eq!(result.index, 12);
 /// ```
 #[derive(Debug)]
-pub struct ManyOne<I, R1, R2, T>
-    where I: Parse<R1, R2, T> {
+pub struct ManyOne<I, R1, R2, T, E = ParseError>
+    where I: Parse<R1, R2, T, E> {
         parser: I,
         _p1: PhantomData<R1>,
         _p2: PhantomData<R2>,
         _p3: PhantomData<T>,
+        _p4: PhantomData<E>,
 }
 
-impl<I, R1, R2, T> ManyOne<I, R1, R2, T> 
-    where I: Parse<R1, R2, T> {
+impl<I, R1, R2, T, E> ManyOne<I, R1, R2, T, E> 
+    where I: Parse<R1, R2, T, E> {
 
     pub fn new(parser: I) -> Self {
-        Self { parser, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData }
+        Self { parser, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData, _p4: PhantomData }
     }
 }
 
-impl<I, R1, R2, T> Parse<R1, R2, T> for ManyOne<I, R1, R2, T> 
-    where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug, 
-        I: Parse<R1,R2,T> {    
+impl<I, R1, R2, T, E> Parse<R1, R2, T, E> for ManyOne<I, R1, R2, T, E> 
+    where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug, E: CombinatorError,
+        I: Parse<R1, R2, T, E> {    
 
-   fn transform(&self, state: State<R1, T>) -> State<R2, T> {
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
 
       let mut results: Vec<R2> = Vec::new();
       let target = Rc::clone(&state.target);
-      let mut final_state: State<R1, T> = State {
+      let mut final_state: State<R1, T, E> = State {
          index: state.index,
          target: Rc::clone(&state.target),
+         position: state.position,
          result: state.result,
+         tracker: state.tracker
       };
 
-      let mut done = false;
-      while !done {
+      let mut last_err = None;
+      while last_err.is_none() {
+         let start = (final_state.index, final_state.position);
          let state = self.parser.transform(final_state);
+         final_state = state.reset();
 
          match state.result.unwrap() {
              Ok(Cardinality::One(res)) => results.push(res),
              Ok(Cardinality::Many(mut res)) => results.append(&mut res),
-             Err(_) => done = true,
-         }
-
-         final_state = State {
-            index: state.index,
-            target: Rc::clone(&target),
-            result: None,
+             Err(err) if err.is_fatal() || err.is_incomplete() => return final_state.forward_err(err),
+             Err(err) => {
+                // drop any input consumed by the failed attempt
+                (final_state.index, final_state.position) = start;
+                last_err = Some(err)
+             },
          }
       }
 
       if results.is_empty() {
-         let err_state = final_state
-            .new_err("ManyOne: Unable to match any input using parser @ index".to_owned());
+         let mut err = ParseError::new(ErrorKind::Mismatch, "ManyOne", final_state.index);
+         err.expected.push("at least one match".to_owned());
+         if let Some(cause) = last_err {
+            match cause.as_parse_error() {
+               Some(parse_err) => err = err.with_cause(parse_err.clone()),
+               // a user defined error is more specific, report it as is
+               None => return final_state.forward_err(cause)
+            }
+         }
 
-         return err_state;
+         return final_state.forward_err(E::from(err));
       }
 
-      let res = State {
+      State {
          index: final_state.index,
          target,
+         position: final_state.position,
          result: Some(Ok(Cardinality::Many(results))),
-      };
-
-
-      res
+         tracker: final_state.tracker
+      }
    }
 }
 
@@ -130,7 +141,9 @@ mod tests {
         let many_one = ManyOne::new(str_parser);
         let result = many_one.run("StuffTest");
         assert!(result.result.is_some());
-        assert!(result.result.unwrap().is_err());
+        let err = result.result.unwrap().unwrap_err();
+        assert_eq!(err.parser, "ManyOne");
+        assert_eq!(err.cause.unwrap().parser, "Str");
         assert_eq!(result.index, 0);
     }
 }
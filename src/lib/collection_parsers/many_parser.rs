@@ -1,5 +1,6 @@
 use crate::models::cardinality::Cardinality;
-use crate::models::parser_traits::Parse;
+use crate::models::parse_error::ParseError;
+use crate::models::parser_traits::{Parse, CombinatorError};
 use crate::models::state::State;
 use std::fmt;
 use std::marker::PhantomData;
@@ -31,60 +32,62 @@ use std::rc::Rc;
 /// assert_eq!(result.index, 12);
 /// ```
 #[derive(Debug)]
-pub struct Many<I, R1, R2, T>
-    where I: Parse<R1, R2, T> {
+pub struct Many<I, R1, R2, T, E = ParseError>
+    where I: Parse<R1, R2, T, E> {
     parser: I,
     _p1: PhantomData<R1>,
     _p2: PhantomData<R2>,
     _p3: PhantomData<T>,
+    _p4: PhantomData<E>,
 }
 
-impl<I, R1, R2, T> Many<I, R1, R2, T>     
-    where I: Parse<R1, R2, T> {
+impl<I, R1, R2, T, E> Many<I, R1, R2, T, E>     
+    where I: Parse<R1, R2, T, E> {
 
     pub fn new(parser: I) -> Self {
-        Self { parser, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData }
+        Self { parser, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData, _p4: PhantomData }
     }
 }
 
-impl<I, R1, R2, T> Parse<R1, R2, T> for Many<I, R1, R2, T> 
-    where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug, 
-        I: Parse<R1,R2,T> {
+impl<I, R1, R2, T, E> Parse<R1, R2, T, E> for Many<I, R1, R2, T, E> 
+    where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug, E: CombinatorError,
+        I: Parse<R1, R2, T, E> {
     
-    fn transform(&self, state: State<R1, T>) -> State<R2, T> {
-        println!("{:?}", self);
-        println!("\t{:?}", state);
-        println!("");
-
+    fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
         let mut results: Vec<R2> = Vec::new();
         let target = Rc::clone(&state.target);
-        let mut final_state: State<R1, T> = State {
+        let mut final_state: State<R1, T, E> = State {
             index: state.index,
             target: Rc::clone(&state.target),
+            position: state.position,
             result: state.result,
+            tracker: state.tracker
         };
 
         let mut done = false;
         while !done {
+            let start = (final_state.index, final_state.position);
             let state = self.parser.transform(final_state);
+            final_state = state.reset();
 
             match state.result.unwrap() {
                 Ok(Cardinality::One(res)) => results.push(res),
                 Ok(Cardinality::Many(mut res)) => results.append(&mut res),
-                Err(_) => done = true,
-            }
-
-            final_state = State {
-                index: state.index,
-                target: Rc::clone(&target),
-                result: None,
+                Err(err) if err.is_fatal() || err.is_incomplete() => return final_state.forward_err(err),
+                Err(_) => {
+                    // drop any input consumed by the failed attempt
+                    (final_state.index, final_state.position) = start;
+                    done = true
+                },
             }
         }
 
         State {
             index: final_state.index,
             target,
+            position: final_state.position,
             result: Some(Ok(Cardinality::Many(results))),
+            tracker: final_state.tracker
         }
     }
 }
@@ -93,7 +96,8 @@ impl<I, R1, R2, T> Parse<R1, R2, T> for Many<I, R1, R2, T>
 mod tests {
     use super::Many;
     use crate::models::parser_traits::Parse;
-    use crate::parsers::str_parser::Str;
+    use crate::collection_parsers::sequence_of_parser::SequenceOf;
+    use crate::parsers::{digits_parser::Digits, str_parser::Str};
 
     #[test]
     fn many_parser_full_run() {
@@ -114,4 +118,24 @@ mod tests {
         assert_eq!(result.result.unwrap().unwrap().unwrap_many().len(), 1);
         assert_eq!(result.index, 4);
     }
+
+    #[test]
+    fn many_parser_reports_farthest_failure() {
+        let item = SequenceOf::new(vec![
+            Box::new(Str::new(" ".to_owned())),
+            Box::new(Digits::new())
+        ]);
+        let list = SequenceOf::new(vec![
+            Box::new(Str::new("(".to_owned())),
+            Box::new(Many::new(item)),
+            Box::new(Str::new(")".to_owned()))
+        ]);
+
+        let result = list.run("( 1 2 x)");
+        let err = result.result.unwrap().unwrap_err();
+        assert_eq!(err.parser, "Digits");
+        assert_eq!(err.index, 6);
+        assert_eq!(result.index, 6);
+        assert_eq!(result.position.column, 7);
+    }
 }
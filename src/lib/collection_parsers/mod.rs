@@ -4,4 +4,51 @@ pub mod many_one_parser;
 pub mod sequence_of_parser;
 pub mod sep_by_parser;
 pub mod sep_by_one_parser;
-pub mod choice_parser;
\ No newline at end of file
+pub mod choice_parser;
+pub mod length_prefixed_parser;
+pub mod count_prefixed_parser;
+
+use std::fmt::Debug;
+
+use crate::models::{
+   cardinality::Cardinality,
+   parse_error::ParseError,
+   parser_traits::{Parse, CombinatorError},
+   state::State
+};
+
+/// A length or count and the state after it
+type Prefix<R, T, E> = (usize, State<R, T, E>);
+
+/// Run `parser` for the length or count prefixing a value, returning it as
+/// a `usize` with the state after the prefix, or the failed state. Negative
+/// or too large prefixes fail at the start of the prefix
+pub(crate) fn read_prefix<I, L1, N, R, S, T, E>(parser: &I, name: &str, state: State<R, T, E>) -> Result<Prefix<R, T, E>, State<S, T, E>>
+   where I: Parse<L1, N, T, E>, N: TryInto<usize> + Copy + Debug,
+      L1: Debug, R: Debug, T: Debug, E: CombinatorError {
+
+   let prefix = parser.transform(state.reset());
+   let after = prefix.reset();
+
+   let message = match prefix.result.unwrap() {
+      Ok(Cardinality::One(size)) => match size.try_into() {
+         Ok(size) => return Ok((size, after)),
+         Err(_) => format!("invalid size {:?}", size)
+      },
+      Ok(Cardinality::Many(_)) => "the size must be a single value".to_owned(),
+      Err(err) => return Err(after.forward_err(err))
+   };
+
+   let err = ParseError::custom(name, state.index, message);
+   Err(state.new_err(E::from(err)))
+}
+
+/// Move the index of an error raised on a part of the target starting at
+/// `offset` to the matching index of the whole target
+pub(crate) fn shift_error<E: CombinatorError>(mut err: E, offset: usize) -> E {
+   if let Some(err) = err.as_parse_error_mut() {
+      err.index += offset;
+   }
+
+   err
+}
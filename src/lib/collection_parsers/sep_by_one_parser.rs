@@ -3,11 +3,13 @@ use std::marker::PhantomData;
 use std::rc::Rc;
 
 use crate::models::cardinality::Cardinality::{One, Many};
-use crate::models::parser_traits::Parse;
+use crate::models::parse_error::{ParseError, ErrorKind};
+use crate::models::parser_traits::{Parse, CombinatorError};
 use crate::models::state::State;
 
 /// # SepByOne:
-/// Parse at least one value separated by a `separator` value
+/// Parse at least one value separated by a `separator` value. Input
+/// consumed by the failed value or by a trailing separator is not kept
 /// 
 /// ### Returns:
 /// A result of type [`Many`]
@@ -31,33 +33,32 @@ use crate::models::state::State;
 /// assert_eq!(result.index, 14);
 /// ```
 #[derive(Debug)]
-pub struct SepByOne<R1,R2,T,S,V>
-   where S: Parse<R1, R2, T>,
-      V: Parse<R1, R2, T> {
+pub struct SepByOne<R1,R2,T,S,V, E = ParseError>
+   where S: Parse<R1, R2, T, E>,
+      V: Parse<R1, R2, T, E> {
    separator: S,
    separated: V,
    _p1: PhantomData<R1>,
    _p2: PhantomData<R2>,
    _p3: PhantomData<T>,
+   _p4: PhantomData<E>,
 }
 
-impl<R1,R2,T,S,V> SepByOne<R1,R2,T, S, V> 
-   where S: Parse<R1, R2, T>,
-      V: Parse<R1, R2, T> {
+impl<R1,R2,T,S,V,E> SepByOne<R1,R2,T,S,V,E> 
+   where S: Parse<R1, R2, T, E>,
+      V: Parse<R1, R2, T, E> {
 
    pub fn new(separator: S, separated: V) -> Self {
-      Self { separator, separated, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData }
+      Self { separator, separated, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData, _p4: PhantomData }
    } 
 }
 
-impl<R1,R2,T,S,V> Parse<R1,R2,T> for SepByOne<R1,R2,T,S,V>
-   where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug,
-      S: Parse<R1, R2, T>,
-      V: Parse<R1, R2, T> {
-      
-   fn transform(&self, state: State<R1, T>) -> State<R2, T> {
-      println!("{:?}", self);
+impl<R1,R2,T,S,V,E> Parse<R1,R2,T, E> for SepByOne<R1,R2,T,S,V,E>
+   where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug, E: CombinatorError,
+      S: Parse<R1, R2, T, E>,
+      V: Parse<R1, R2, T, E> {
       
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
       let contains_error = state.is_error();
       if contains_error {
          return State::from_err_state(state);
@@ -65,47 +66,59 @@ impl<R1,R2,T,S,V> Parse<R1,R2,T> for SepByOne<R1,R2,T,S,V>
 
       let target = Rc::clone(&state.target);
       let mut results: Vec<R2> = Vec::new();
-      let mut final_state: State<R1, T> = State {
-         index: state.index,
-         target: Rc::clone(&state.target),
-         result: None
-      };
+      let mut final_state: State<R1, T, E> = state.reset();
 
+      let mut last_err = None;
+      // the end of the last parsed value, input consumed by a failed value
+      // or by a trailing separator is dropped
+      let mut end = (final_state.index, final_state.position);
       loop {
          let thing_we_want_state = self.separated.transform(final_state);
-         final_state = State {
-            index: thing_we_want_state.index,
-            target: Rc::clone(&target),
-            result: None,
-         };
+         final_state = thing_we_want_state.reset();
 
          match thing_we_want_state.result.unwrap() {
             Ok(One(res)) => results.push(res),
             Ok(Many(mut res)) => results.append(&mut res),
-            Err(_) => break
+            Err(err) if err.is_fatal() || err.is_incomplete() => return final_state.forward_err(err),
+            Err(err) => {
+               last_err = Some(err);
+               break
+            }
          }
 
+         end = (final_state.index, final_state.position);
          let separator_state = self.separator.transform(final_state);
-         final_state = State {
-            index: separator_state.index,
-            target: Rc::clone(&target),
-            result: None,
-         };
-
-         if separator_state.result.unwrap().is_err() {
-            break;
+         final_state = separator_state.reset();
+
+         match separator_state.result.unwrap() {
+            Err(err) if err.is_fatal() || err.is_incomplete() => return final_state.forward_err(err),
+            Err(_) => break,
+            Ok(_) => {}
          }
       }
 
+      (final_state.index, final_state.position) = end;
+
       if results.is_empty() {
-         return final_state
-            .new_err("manyOne: Unable to match any input using parser @ index".to_owned());
+         let mut err = ParseError::new(ErrorKind::Mismatch, "SepByOne", final_state.index);
+         err.expected.push("at least one separated value".to_owned());
+         if let Some(cause) = last_err {
+            match cause.as_parse_error() {
+               Some(parse_err) => err = err.with_cause(parse_err.clone()),
+               // a user defined error is more specific, report it as is
+               None => return final_state.forward_err(cause)
+            }
+         }
+
+         return final_state.forward_err(E::from(err));
       }
 
       State {
          index: final_state.index,
          target,
+         position: final_state.position,
          result: Some(Ok(Many(results))),
+         tracker: final_state.tracker
      }
    }
 }
@@ -136,7 +149,7 @@ mod tests {
 
       assert!(result.result.is_some());
       assert_eq!(result.result.unwrap().unwrap().unwrap_many().len(), 2);
-      assert_eq!(result.index, 10);
+      assert_eq!(result.index, 9);
    }
 
    #[test]
@@ -147,7 +160,9 @@ mod tests {
       let result = sep_parser.run("");
 
       assert!(result.result.is_some());
-      assert!(result.result.unwrap().is_err());
+      let err = result.result.unwrap().unwrap_err();
+      assert_eq!(err.parser, "SepByOne");
+      assert!(err.cause.unwrap().is_end_of_input());
       assert_eq!(result.index, 0);
    }
 }
\ No newline at end of file
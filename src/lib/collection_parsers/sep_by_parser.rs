@@ -3,13 +3,15 @@ use std::marker::PhantomData;
 use std::rc::Rc;
 
 use crate::models::cardinality::Cardinality::{One, Many};
-use crate::models::parser_traits::Parse;
+use crate::models::parse_error::ParseError;
+use crate::models::parser_traits::{Parse, CombinatorError};
 use crate::models::state::State;
 
 /// # SepBy:
 /// Parse zero or more values separated by a `separator` value, the parser
 /// will run until it fails to parse the next value but will not return any
-/// error message instead it will return the parsed values
+/// error message instead it will return the parsed values. Input consumed
+/// by the failed value or by a trailing separator is not kept
 /// 
 /// ### Returns:
 /// A result of type [`Many`]
@@ -33,37 +35,35 @@ use crate::models::state::State;
 /// assert_eq!(result.index, 14);
 /// ```
 #[derive(Debug)]
-pub struct SepBy<R1,R2,T, S, V>
-   where S: Parse<R1, R2, T>,
-      V: Parse<R1, R2, T> {
+pub struct SepBy<R1,R2,T,S,V,E = ParseError>
+   where S: Parse<R1, R2, T, E>,
+      V: Parse<R1, R2, T, E> {
    separator: S,
    separated: V,
    _p1: PhantomData<R1>,
    _p2: PhantomData<R2>,
    _p3: PhantomData<T>,
+   _p4: PhantomData<E>,
 }
 
-impl<R1,R2,T,S,V> SepBy<R1,R2,T, S, V> 
-   where S: Parse<R1, R2, T>,
-      V: Parse<R1, R2, T> {
+impl<R1,R2,T,S,V,E> SepBy<R1,R2,T,S,V,E> 
+   where S: Parse<R1, R2, T, E>,
+      V: Parse<R1, R2, T, E> {
    /// Instantiate a [`SepBy`] parser 
    /// 
    /// ## Args:
    /// * `separator` - A parser that will separate the needed value
    /// * `separated` - The parser for the needed value separated by the `separator`
    pub fn new(separator: S, separated: V) -> Self {
-      Self { separator, separated, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData }
+      Self { separator, separated, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData, _p4: PhantomData }
    } 
 }
 
-impl<R1,R2,T,S,V> Parse<R1,R2,T> for SepBy<R1,R2,T,S,V>
-   where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug,
-      S: Parse<R1, R2, T>,
-      V: Parse<R1, R2, T> {
-   fn transform(&self, state: State<R1, T>) -> State<R2, T> {
-      println!("{:?}", self);
-      println!("{:?}", state);
-
+impl<R1,R2,T,S,V,E> Parse<R1,R2,T, E> for SepBy<R1,R2,T,S,V,E>
+   where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug, E: CombinatorError,
+      S: Parse<R1, R2, T, E>,
+      V: Parse<R1, R2, T, E> {
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
       let contains_error = state.is_error();
       if contains_error {
          return State::from_err_state(state);
@@ -71,48 +71,48 @@ impl<R1,R2,T,S,V> Parse<R1,R2,T> for SepBy<R1,R2,T,S,V>
 
       let target = Rc::clone(&state.target);
       let mut results: Vec<R2> = Vec::new();
-      let mut final_state: State<R1, T> = State {
-         index: state.index,
-         target: Rc::clone(&state.target),
-         result: None
-      };
+      let mut final_state: State<R1, T, E> = state.reset();
 
+      // the end of the last parsed value, input consumed by a failed value
+      // or by a trailing separator is dropped
+      let mut end = (final_state.index, final_state.position);
       loop {
          let thing_we_want_state = self.separated.transform(final_state);
-         final_state = State {
-            index: thing_we_want_state.index,
-            target: Rc::clone(&target),
-            result: None,
-         };
+         final_state = thing_we_want_state.reset();
 
          match thing_we_want_state.result.unwrap() {
             Ok(One(res)) => results.push(res),
             Ok(Many(mut res)) => results.append(&mut res),
+            Err(err) if err.is_fatal() || err.is_incomplete() => return final_state.forward_err(err),
             Err(_) => break
          }
 
+         end = (final_state.index, final_state.position);
          let separator_state = self.separator.transform(final_state);
-         final_state = State {
-            index: separator_state.index,
-            target: Rc::clone(&target),
-            result: None,
-         };
-
-         if separator_state.result.unwrap().is_err() {
-            break;
+         final_state = separator_state.reset();
+
+         match separator_state.result.unwrap() {
+            Err(err) if err.is_fatal() || err.is_incomplete() => return final_state.forward_err(err),
+            Err(_) => break,
+            Ok(_) => {}
          }
       }
 
+      (final_state.index, final_state.position) = end;
+
       State {
          index: final_state.index,
          target,
+         position: final_state.position,
          result: Some(Ok(Many(results))),
+         tracker: final_state.tracker
      }
    }
 }
 
 #[cfg(test)]
 mod tests {
+   use crate::collection_parsers::sequence_of_parser::SequenceOf;
    use crate::parsers::str_parser::Str;
    use super::*;
 
@@ -137,7 +137,7 @@ mod tests {
 
       assert!(result.result.is_some());
       assert_eq!(result.result.unwrap().unwrap().unwrap_many().len(), 2);
-      assert_eq!(result.index, 10);
+      assert_eq!(result.index, 9);
    }
 
    #[test]
@@ -151,4 +151,17 @@ mod tests {
       assert_eq!(result.result.unwrap().unwrap().unwrap_many().len(), 0);
       assert_eq!(result.index, 0);
    }
-}
\ No newline at end of file
+
+   #[test]
+   fn failed_value_is_rolled_back() {
+      let value = SequenceOf::new(vec![
+         Box::new(Str::new("a".to_owned())),
+         Box::new(Str::new("b".to_owned()))
+      ]);
+      let sep_parser = SepBy::new(Str::new(",".to_owned()), value);
+      let result = sep_parser.run("ab,ax");
+
+      assert_eq!(result.result.unwrap().unwrap().unwrap_many(), vec!["a", "b"]);
+      assert_eq!(result.index, 2);
+   }
+}
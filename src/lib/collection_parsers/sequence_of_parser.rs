@@ -1,6 +1,6 @@
 use std::fmt::{Debug, self};
 use std::rc::Rc;
-use crate::models::{parser_traits::Parse, state::State};
+use crate::models::{parse_error::ParseError, parser_traits::{Parse, CombinatorError}, state::State};
 use crate::models::cardinality::Cardinality::{One, Many};
 
 
@@ -28,14 +28,14 @@ use crate::models::cardinality::Cardinality::{One, Many};
 /// assert!(result.result.is_some());
 /// ```
 #[derive(Debug) ]
-pub struct SequenceOf<R1,R2,T> {
-   parsers: Vec<Box<dyn Parse<R1,R2,T>>>
+pub struct SequenceOf<R1,R2,T,E = ParseError> {
+   parsers: Vec<Box<dyn Parse<R1,R2,T,E>>>
 }
 
-impl<R1,R2,T> SequenceOf<R1,R2,T> 
+impl<R1,R2,T,E> SequenceOf<R1,R2,T,E> 
    where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug {
 
-   pub fn new(parsers: Vec<Box<dyn Parse<R1,R2,T>>>) -> Self {
+   pub fn new(parsers: Vec<Box<dyn Parse<R1,R2,T,E>>>) -> Self {
       if parsers.is_empty() {
          panic!("SequenceOf: parsers must not be empty")
       }
@@ -43,15 +43,15 @@ impl<R1,R2,T> SequenceOf<R1,R2,T>
       Self { parsers }
    }
 
-   pub fn push_parser(&mut self, parser: Box<dyn Parse<R1,R2,T>>) {
+   pub fn push_parser(&mut self, parser: Box<dyn Parse<R1,R2,T,E>>) {
       self.parsers.push(parser);
    }
 }
 
-impl<R1,R2,T> Parse<R1,R2,T> for SequenceOf<R1,R2,T> 
-   where R1: Debug, R2: Debug, T:Debug {
+impl<R1,R2,T,E> Parse<R1,R2,T,E> for SequenceOf<R1,R2,T,E> 
+   where R1: Debug, R2: Debug, T:Debug, E: CombinatorError {
       
-   fn transform(&self, state: State<R1, T>) -> State<R2, T> {
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
       let contains_error = state.is_error();
 
       if contains_error {         
@@ -60,51 +60,35 @@ impl<R1,R2,T> Parse<R1,R2,T> for SequenceOf<R1,R2,T>
 
       let mut results: Vec<R2> = Vec::with_capacity(self.parsers.len());
 
-      let mut final_state: State<R1, T> = State {
-         index: state.index,
-         target: Rc::clone(&state.target),
-         result: None
-      };
+      let mut final_state: State<R1, T, E> = state.reset();
 
       let target = Rc::clone(&state.target);
 
       for parser in &self.parsers {
          let state = parser.transform(final_state);
+         final_state = state.reset();
 
          match state.result.unwrap() {
             Ok(One(res)) => results.push(res),
             Ok(Many(mut res)) => results.append(&mut res),
-            Err(err) => {
-               let err_state = State {
-                  index: state.index,
-                  target: state.target,
-                  result: Some(Err(err))
-               };
-
-               return err_state;
-            }
-         }
-
-         final_state = State {
-            index: state.index,
-            target: Rc::clone(&target),
-            result: None
+            Err(err) => return final_state.forward_err(err)
          }
       }
 
 
-      let res = State { 
+      State { 
          index: final_state.index, 
          target, 
-         result: Some(Ok(Many(results))) 
-      };
-
-      res
+         position: final_state.position,
+         result: Some(Ok(Many(results))),
+         tracker: final_state.tracker
+      }
    }
 }
 
 #[cfg(test)]
 mod tests {
+   use crate::models::position::Position;
    use crate::parsers::str_parser::Str;
    use super::*;
 
@@ -131,4 +115,43 @@ mod tests {
       assert!(result.result.unwrap().is_err());
       assert_eq!(result.index, 5);
    }
+
+   #[test]
+   fn test_position_tracking() {
+      let s1 = Box::new(Str::new("Test1".to_owned()));
+      let s2 = Box::new(Str::new("\r\n".to_owned()));
+      let s3 = Box::new(Str::new("Test2".to_owned()));
+      let seq = SequenceOf::new(vec![s1,s2,s3]);
+
+      let result = seq.run("Test1\r\nTest2");
+      assert_eq!(result.position, Position { line: 2, column: 6 });
+
+      let result = seq.run("Test1\r\nTest3");
+      assert!(result.is_error());
+      assert_eq!(result.position, Position { line: 2, column: 1 });
+   }
+
+   #[test]
+   fn test_mixed_primitives() {
+      use std::rc::Rc;
+      use crate::models::input::Input;
+      use crate::parsers::{digits_parser::Digits, letters_parser::Letters};
+
+      fn assignment<T: Input>() -> SequenceOf<String, String, T> {
+         SequenceOf::new(vec![
+            Box::new(Letters::new()),
+            Box::new(Str::new("=".to_owned())),
+            Box::new(Digits::new())
+         ])
+      }
+
+      let expected = vec!["port", "=", "8080"];
+      let shared: Rc<str> = Rc::from("port=8080");
+      let bytes: &[u8] = b"port=8080";
+
+      assert_eq!(assignment().run("port=8080").result.unwrap().unwrap().unwrap_many(), expected);
+      assert_eq!(assignment().run("port=8080".to_owned()).result.unwrap().unwrap().unwrap_many(), expected);
+      assert_eq!(assignment().run(shared).result.unwrap().unwrap().unwrap_many(), expected);
+      assert_eq!(assignment().run(bytes).result.unwrap().unwrap().unwrap_many(), expected);
+   }
 }
@@ -0,0 +1,200 @@
+use std::fmt::Write;
+
+use crate::models::{input::Input, parse_error::{ParseError, ErrorKind}, position::Position, state::State};
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// # Diagnostic
+/// Renders a failed [`State`] as a compiler style report pointing at the
+/// offending line of the target
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::diagnostics::Diagnostic;
+/// use parser_combinator::parsers::str_parser::Str;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let state = Str::new("name".to_owned()).run("nmae = 1");
+/// let report = Diagnostic::new("config.toml", false).render(&state).unwrap();
+///
+/// assert_eq!(report, "\
+/// error: unexpected input
+///  --> config.toml:1:1
+///   |
+/// 1 | nmae = 1
+///   | ^^^^ expected 'name' but found 'nmae'
+///   = help: did you mean 'name'?
+/// ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+   /// The name of the parsed file displayed next to the line and column
+   pub file_name: String,
+   /// Highlight the report with ANSI color codes
+   pub color: bool
+}
+
+impl Diagnostic {
+   pub fn new(file_name: &str, color: bool) -> Self {
+      Self { file_name: file_name.to_owned(), color }
+   }
+
+   /// Render the error of `state`, returns `None` if the state is not an error
+   pub fn render<R, T: Input>(&self, state: &State<R, T>) -> Option<String> {
+      let target = &*state.target;
+
+      match state.result.as_ref() {
+         Some(Err(err)) => Some(self.render_error(&target.text(0, target.len()), err)),
+         _ => None
+      }
+   }
+
+   /// Render `err` against the text of the `target` it was raised on
+   pub fn render_error(&self, target: &str, err: &ParseError) -> String {
+      // point at the start of the char when the index is inside of it
+      let mut index = err.index.min(target.len());
+      while !target.is_char_boundary(index) {
+         index -= 1;
+      }
+
+      let position = Position::from_index(target, index);
+
+      let line_start = target[..index].rfind('\n').map(|i| i + 1).unwrap_or(0);
+      let line_end = target[index..].find('\n').map(|i| index + i).unwrap_or(target.len());
+      let line = target[line_start..line_end].trim_end_matches('\r');
+
+      let line_number = position.line.to_string();
+      let gutter = " ".repeat(line_number.len());
+
+      // keep tabs in the padding so the caret lines up with the source line
+      let padding: String = line[..(index - line_start).min(line.len())]
+         .chars()
+         .map(|c| if c == '\t' { '\t' } else { ' ' })
+         .collect();
+
+      let width = err.found
+         .as_ref()
+         .map(|found| found.chars().count())
+         .unwrap_or(0)
+         .max(1);
+
+      let mut report = String::new();
+      let _ = writeln!(report, "{}: {}", self.paint(RED, "error"), self.paint(BOLD, &headline(err)));
+      let _ = writeln!(report, "{}{} {}:{}", gutter, self.paint(BLUE, "-->"), self.file_name, position);
+      let _ = writeln!(report, "{} {}", gutter, self.paint(BLUE, "|"));
+      let _ = writeln!(report, "{} {} {}", self.paint(BLUE, &line_number), self.paint(BLUE, "|"), line);
+      let _ = writeln!(report, "{} {} {}{} {}",
+         gutter,
+         self.paint(BLUE, "|"),
+         padding,
+         self.paint(RED, &"^".repeat(width)),
+         self.paint(RED, &summary(err)));
+
+      if let Some(hint) = err.did_you_mean() {
+         let _ = writeln!(report, "{} {} help: {}", gutter, self.paint(BLUE, "="), hint);
+      }
+
+      let mut cause = err.cause.as_deref();
+      while let Some(inner) = cause {
+         let _ = writeln!(report, "{} {} caused by: {}", gutter, self.paint(BLUE, "="), inner);
+         cause = inner.cause.as_deref();
+      }
+
+      report
+   }
+
+   fn paint(&self, color: &str, text: &str) -> String {
+      if self.color {
+         format!("{}{}{}", color, text, RESET)
+      } else {
+         text.to_owned()
+      }
+   }
+}
+
+/// The first line of the report describing the kind of failure
+fn headline(err: &ParseError) -> String {
+   let headline = match &err.kind {
+      ErrorKind::UnexpectedEndOfInput => "unexpected end of input".to_owned(),
+      ErrorKind::Mismatch => "unexpected input".to_owned(),
+      ErrorKind::Custom(message) => message.clone(),
+      ErrorKind::Incomplete(needed) => format!("incomplete input, {} more bytes needed", needed),
+      ErrorKind::Overflow(bits) => format!("number does not fit in {} bits", bits),
+   };
+
+   if err.context.is_empty() {
+      return headline;
+   }
+
+   format!("{} while parsing {}", headline, err.context.join(" > "))
+}
+
+/// The expected/found summary displayed next to the caret
+fn summary(err: &ParseError) -> String {
+   let expected = match err.expected.as_slice() {
+      [] => String::new(),
+      [single] => format!("expected {}", single),
+      many => format!("expected one of {}", many.join(", ")),
+   };
+
+   match (&err.found, expected.is_empty()) {
+      (Some(found), true) => format!("found '{}'", found),
+      (Some(found), false) => format!("{} but found '{}'", expected, found),
+      (None, false) => format!("{} but found end of input", expected),
+      (None, true) => String::new(),
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::sequence_of_parser::SequenceOf;
+   use crate::models::parser_traits::Parse;
+   use crate::parsers::{digits_parser::Digits, str_parser::Str};
+   use super::Diagnostic;
+
+   #[test]
+   fn render_second_line() {
+      let header = Box::new(Str::new("[server]\r\n".to_owned()));
+      let key = Box::new(Str::new("port = ".to_owned()));
+      let value = Box::new(Digits::new());
+      let seq = SequenceOf::new(vec![header, key, value]);
+
+      let state = seq.run("[server]\r\nport = abc\r\n");
+      let report = Diagnostic::new("app.toml", false).render(&state).unwrap();
+
+      assert_eq!(report, "\
+error: unexpected input
+ --> app.toml:2:8
+  |
+2 | port = abc
+  |        ^ expected digits but found 'a'
+");
+   }
+
+   #[test]
+   fn render_end_of_input() {
+      let state = Str::new("abc".to_owned()).run("ab");
+      let report = Diagnostic::new("in", false).render(&state).unwrap();
+
+      assert!(report.contains("1 | ab\n"));
+      assert!(report.contains("  | ^ expected 'abc' but found end of input\n"));
+   }
+
+   #[test]
+   fn render_color() {
+      let state = Str::new("abc".to_owned()).run("xyz");
+      let report = Diagnostic::new("in", true).render(&state).unwrap();
+
+      assert!(report.starts_with("\x1b[1;31merror\x1b[0m"));
+   }
+
+   #[test]
+   fn render_success_is_none() {
+      let state = Str::new("abc".to_owned()).run("abc");
+      assert!(Diagnostic::new("in", false).render(&state).is_none());
+   }
+}
@@ -0,0 +1,309 @@
+use std::{borrow::Cow, fmt, fs::File, io::{self, Read}, path::{Path, PathBuf}};
+
+use crate::models::input::{Input, str_text, str_unit_len};
+
+/// # FromFile
+/// The targets [`crate::models::parser_traits::Parse::run_file`] and
+/// [`crate::models::parser_traits::Parse::run_file_mapped`] can open
+pub trait FromFile: Input + Sized {
+   fn open_file(path: &Path) -> io::Result<Self>;
+
+   /// # Safety
+   /// The file must not be modified or truncated while the target is alive
+   unsafe fn open_file_mapped(path: &Path) -> io::Result<Self>;
+}
+
+/// The bytes of a file, mapped in memory or read into a buffer
+enum Contents {
+   #[cfg(feature = "mmap")]
+   Mapped(memmap2::Mmap),
+   Buffered(Vec<u8>)
+}
+
+impl Contents {
+   /// # Safety
+   /// The file must not be modified or truncated while the contents are alive
+   unsafe fn map(path: &Path) -> io::Result<Self> {
+      let file = File::open(path)?;
+
+      #[cfg(feature = "mmap")]
+      {
+         // SAFETY: the map is read only and the caller guarantees the file
+         // does not change while it is alive
+         if let Ok(map) = unsafe { memmap2::Mmap::map(&file) } {
+            return Ok(Contents::Mapped(map));
+         }
+      }
+
+      // e.g. a pipe or a file system without mmap support
+      Self::buffer(file)
+   }
+
+   fn buffer(mut file: File) -> io::Result<Self> {
+      let mut buffer = Vec::new();
+      file.read_to_end(&mut buffer)?;
+      Ok(Contents::Buffered(buffer))
+   }
+
+   fn bytes(&self) -> &[u8] {
+      match self {
+         #[cfg(feature = "mmap")]
+         Contents::Mapped(map) => map,
+         Contents::Buffered(buffer) => buffer
+      }
+   }
+}
+
+/// # FileInput
+/// The contents of a file as a binary target, read byte by byte like a
+/// `&[u8]`. [`FileInput::open`] reads the file into a buffer, with the
+/// `mmap` feature (enabled by default) [`FileInput::open_mapped`] and
+/// [`crate::models::parser_traits::Parse::run_file_mapped`] map it in memory
+/// instead, so parsing a large file only keeps the pages being read in memory
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::byte_parsers::tag_parser::Tag;
+/// use parser_combinator::file_input::FileInput;
+/// use parser_combinator::models::{parser_traits::Parse, state::State};
+///
+/// # let path = std::env::temp_dir().join("file_input_doc.bin");
+/// # std::fs::write(&path, b"\x7fELF\x02").unwrap();
+/// // SAFETY: nothing writes to the file while it is parsed
+/// let state: State<_, FileInput> = unsafe { Tag::new(b"\x7fELF").run_file_mapped(&path) }.unwrap();
+///
+/// assert_eq!(state.result.unwrap().unwrap().unwrap_one(), b"\x7fELF");
+/// assert_eq!(state.target.is_mapped(), cfg!(feature = "mmap"));
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct FileInput {
+   path: PathBuf,
+   contents: Contents
+}
+
+impl FileInput {
+   /// Read the whole file at `path` into memory
+   pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+      let path = path.as_ref();
+      Ok(Self { path: path.to_owned(), contents: Contents::buffer(File::open(path)?)? })
+   }
+
+   /// Open the file at `path`, mapping it in memory if possible and reading
+   /// it into a buffer otherwise
+   ///
+   /// # Safety
+   /// The file must not be modified or truncated, by this process or any
+   /// other, while the returned [`FileInput`] is alive. A truncated file
+   /// raises `SIGBUS` when its missing pages are read, a modified one
+   /// changes the target while it is parsed
+   pub unsafe fn open_mapped<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+      let path = path.as_ref();
+      // SAFETY: forwarded to the caller
+      Ok(Self { path: path.to_owned(), contents: unsafe { Contents::map(path)? } })
+   }
+
+   pub fn path(&self) -> &Path {
+      &self.path
+   }
+
+   pub fn as_bytes(&self) -> &[u8] {
+      self.contents.bytes()
+   }
+
+   /// Returns `true` if the file is mapped in memory rather than read into a buffer
+   pub fn is_mapped(&self) -> bool {
+      !matches!(self.contents, Contents::Buffered(_))
+   }
+}
+
+impl fmt::Debug for FileInput {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      f.debug_struct("FileInput")
+         .field("path", &self.path)
+         .field("len", &self.contents.bytes().len())
+         .field("mapped", &self.is_mapped())
+         .finish()
+   }
+}
+
+impl Input for FileInput {
+   fn len(&self) -> usize { self.as_bytes().len() }
+
+   fn chunk(&self, index: usize) -> &[u8] { &self.as_bytes()[index..] }
+
+   fn unit_len(&self, index: usize) -> usize {
+      if index < self.len() { 1 } else { 0 }
+   }
+}
+
+impl FromFile for FileInput {
+   fn open_file(path: &Path) -> io::Result<Self> { Self::open(path) }
+
+   // SAFETY: forwarded to the caller
+   unsafe fn open_file_mapped(path: &Path) -> io::Result<Self> { unsafe { Self::open_mapped(path) } }
+}
+
+/// # TextFileInput
+/// The contents of a UTF-8 file as a text target, read char by char like a
+/// `&str`. The file is opened as a [`FileInput`] and validated once, a file
+/// that is not valid UTF-8 fails to open with [`io::ErrorKind::InvalidData`].
+/// As with [`FileInput`], only [`TextFileInput::open_mapped`] maps the file
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::file_input::TextFileInput;
+/// use parser_combinator::models::{parser_traits::Parse, state::State};
+/// use parser_combinator::parsers::letters_parser::Letters;
+///
+/// # let path = std::env::temp_dir().join("text_file_input_doc.txt");
+/// # std::fs::write(&path, "größe = 2").unwrap();
+/// let state: State<_, TextFileInput> = Letters::new().unicode().run_file(&path).unwrap();
+///
+/// assert_eq!(state.result.unwrap().unwrap().unwrap_one(), "größe");
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct TextFileInput {
+   file: FileInput
+}
+
+impl TextFileInput {
+   /// Read the whole file at `path` into memory
+   pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+      Self::validate(FileInput::open(path)?)
+   }
+
+   /// Open the file at `path`, mapping it in memory if possible and reading
+   /// it into a buffer otherwise
+   ///
+   /// # Safety
+   /// The same as [`FileInput::open_mapped`]. The contents are validated as
+   /// UTF-8 once, a change to the file would also let [`TextFileInput::as_str`]
+   /// return invalid UTF-8
+   pub unsafe fn open_mapped<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+      // SAFETY: forwarded to the caller
+      Self::validate(unsafe { FileInput::open_mapped(path)? })
+   }
+
+   fn validate(file: FileInput) -> io::Result<Self> {
+      std::str::from_utf8(file.as_bytes())
+         .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+      Ok(Self { file })
+   }
+
+   pub fn as_str(&self) -> &str {
+      // SAFETY: the contents were validated when the file was opened, a
+      // buffer never changes and the caller of open_mapped guarantees a
+      // mapped file does not either
+      unsafe { std::str::from_utf8_unchecked(self.file.as_bytes()) }
+   }
+
+   pub fn file(&self) -> &FileInput {
+      &self.file
+   }
+}
+
+impl Input for TextFileInput {
+   fn len(&self) -> usize { self.file.len() }
+
+   fn chunk(&self, index: usize) -> &[u8] { self.file.chunk(index) }
+
+   fn unit_len(&self, index: usize) -> usize { str_unit_len(self.as_str(), index) }
+
+   fn is_boundary(&self, index: usize) -> bool { self.as_str().is_char_boundary(index) }
+
+   fn is_text(&self) -> bool { true }
+
+   fn text(&self, start: usize, end: usize) -> Cow<'_, str> { str_text(self.as_str(), start, end) }
+}
+
+impl FromFile for TextFileInput {
+   fn open_file(path: &Path) -> io::Result<Self> { Self::open(path) }
+
+   // SAFETY: forwarded to the caller
+   unsafe fn open_file_mapped(path: &Path) -> io::Result<Self> { unsafe { Self::open_mapped(path) } }
+}
+
+#[cfg(test)]
+mod tests {
+   use std::{fs, io, path::PathBuf};
+
+   use crate::collection_parsers::sep_by_parser::SepBy;
+   use crate::models::{parser_traits::Parse, state::State};
+   use crate::parsers::{digits_parser::Digits, str_parser::Str};
+   use super::{FileInput, TextFileInput};
+
+   /// A file in the temp directory removed once dropped
+   struct TempFile(PathBuf);
+
+   impl TempFile {
+      fn new(name: &str, contents: &[u8]) -> Self {
+         let path = std::env::temp_dir().join(format!("parser_combinator_{}_{}", std::process::id(), name));
+         fs::write(&path, contents).unwrap();
+         TempFile(path)
+      }
+   }
+
+   impl Drop for TempFile {
+      fn drop(&mut self) {
+         let _ = fs::remove_file(&self.0);
+      }
+   }
+
+   #[test]
+   fn run_file_parses_text() {
+      let file = TempFile::new("numbers.txt", b"1,22,333");
+      let parser = SepBy::new(Str::new(",".to_owned()), Digits::new());
+      let state: State<_, TextFileInput> = parser.run_file(&file.0).unwrap();
+
+      assert_eq!(state.result.unwrap().unwrap().unwrap_many(), vec!["1", "22", "333"]);
+      assert_eq!(state.index, 8);
+      assert!(!state.target.file().is_mapped());
+   }
+
+   #[test]
+   fn run_file_mapped_maps_text() {
+      let file = TempFile::new("mapped.txt", b"4,55");
+      let parser = SepBy::new(Str::new(",".to_owned()), Digits::new());
+
+      // SAFETY: the file is private to the test and not modified
+      let state: State<_, TextFileInput> = unsafe { parser.run_file_mapped(&file.0) }.unwrap();
+
+      assert_eq!(state.result.unwrap().unwrap().unwrap_many(), vec!["4", "55"]);
+      assert_eq!(state.target.file().is_mapped(), cfg!(feature = "mmap"));
+   }
+
+   #[test]
+   fn mapped_matches_open() {
+      let file = TempFile::new("bytes.bin", &[0x00, 0xff, 0x10]);
+
+      // SAFETY: the file is private to the test and not modified
+      let mapped = unsafe { FileInput::open_mapped(&file.0) }.unwrap();
+      let read = FileInput::open(&file.0).unwrap();
+
+      assert_eq!(mapped.is_mapped(), cfg!(feature = "mmap"));
+      assert!(!read.is_mapped());
+      assert_eq!(mapped.as_bytes(), read.as_bytes());
+      assert_eq!(read.as_bytes(), &[0x00, 0xff, 0x10]);
+   }
+
+   #[test]
+   fn text_file_must_be_utf8() {
+      let file = TempFile::new("invalid.txt", &[0x61, 0xff]);
+
+      let err = TextFileInput::open(&file.0).unwrap_err();
+      assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+      assert!(FileInput::open(&file.0).is_ok());
+   }
+
+   #[test]
+   fn empty_file() {
+      let file = TempFile::new("empty.txt", b"");
+      let state: State<_, TextFileInput> = Digits::new().run_file(&file.0).unwrap();
+
+      assert!(state.result.unwrap().unwrap_err().is_end_of_input());
+   }
+}
@@ -15,7 +15,13 @@
 //    - One
 
 pub mod bit_parsers;
+pub mod byte_parsers;
+pub mod token_parsers;
 pub mod collection_parsers;
 pub mod parsers;
 pub mod parser_helpers;
 pub mod models;
+pub mod diagnostics;
+pub mod streaming;
+pub mod file_input;
+pub mod chunked_text;
@@ -0,0 +1,39 @@
+use std::fmt;
+
+/// # Endian
+/// The order of the parts of a number: the bytes of a number read by
+/// [`crate::byte_parsers::number_parser::Number`], or the bits of a field
+/// read by [`crate::bit_parsers::uint_parser::Uint`] and
+/// [`crate::bit_parsers::int_parser::Int`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endian {
+   /// The most significant part first, the order of network protocols
+   #[default]
+   Big,
+   /// The least significant part first, the order of most file formats
+   Little,
+   /// The order of the target platform
+   Native
+}
+
+impl Endian {
+   /// [`Endian::Big`] or [`Endian::Little`], [`Endian::Native`] being
+   /// replaced by the order of the target platform
+   pub fn resolve(self) -> Self {
+      match self {
+         Endian::Native if cfg!(target_endian = "big") => Endian::Big,
+         Endian::Native => Endian::Little,
+         endian => endian
+      }
+   }
+}
+
+impl fmt::Display for Endian {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      match self {
+         Endian::Big => write!(f, "big"),
+         Endian::Little => write!(f, "little"),
+         Endian::Native => write!(f, "native")
+      }
+   }
+}
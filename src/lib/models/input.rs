@@ -0,0 +1,245 @@
+use std::{borrow::Cow, fmt::Debug, rc::Rc};
+
+/// # Input
+/// The target types the built in parsers run on, so parsers written for
+/// text and binary data can be combined in a single grammar. The indexes
+/// of a [`super::state::State`] are byte offsets in the target.
+///
+/// Text targets (`&str`, `String`, `Rc<str>`) are read char by char,
+/// binary targets (`&[u8]`, `Vec<u8>`) byte by byte. A target does not
+/// have to be contiguous: its bytes are read one chunk at a time, e.g. the
+/// chunks of a rope (see [`crate::chunked_text::ChunkedText`]), and the
+/// parsers match across the chunk boundaries
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::models::input::Input;
+///
+/// let text = "añb";
+/// assert_eq!(text.unit_len(1), 2);
+/// assert_eq!(text.text(1, 3), "ñ");
+///
+/// let bytes: &[u8] = &[0x61, 0xff];
+/// assert_eq!(bytes.unit_len(1), 1);
+/// assert_eq!(bytes.text(0, 2), "a\u{fffd}");
+/// ```
+pub trait Input: Debug {
+   /// The length of the target in bytes
+   fn len(&self) -> usize;
+
+   /// Returns `true` if the target holds no bytes
+   fn is_empty(&self) -> bool {
+      self.len() == 0
+   }
+
+   /// The bytes from `index` to the end of the chunk holding it, empty at
+   /// the end of the target. Contiguous targets are a single chunk
+   fn chunk(&self, index: usize) -> &[u8];
+
+   /// The bytes `start..end`, borrowed when they are in a single chunk
+   fn slice(&self, start: usize, end: usize) -> Cow<'_, [u8]> {
+      let first = self.chunk(start);
+      if end - start <= first.len() {
+         return Cow::Borrowed(&first[..end - start]);
+      }
+
+      let mut bytes = Vec::with_capacity(end - start);
+      while start + bytes.len() < end {
+         let chunk = self.chunk(start + bytes.len());
+         if chunk.is_empty() {
+            break;
+         }
+
+         let len = chunk.len().min(end - start - bytes.len());
+         bytes.extend_from_slice(&chunk[..len]);
+      }
+
+      Cow::Owned(bytes)
+   }
+
+   /// The byte at `index`, `None` at or past the end of the target
+   fn byte_at(&self, index: usize) -> Option<u8> {
+      if index >= self.len() {
+         return None;
+      }
+
+      self.chunk(index).first().copied()
+   }
+
+   /// The length in bytes of the unit (a char for text, a byte for binary
+   /// data) starting at `index`. Returns 0 at the end of the target
+   fn unit_len(&self, index: usize) -> usize;
+
+   /// Returns `true` if the target can be split at `index`, text targets
+   /// can only be split on a char boundary
+   fn is_boundary(&self, index: usize) -> bool {
+      index <= self.len()
+   }
+
+   /// Returns `true` for text targets. Positions are only tracked on text,
+   /// a line and column mean nothing for binary data
+   fn is_text(&self) -> bool {
+      false
+   }
+
+   /// Returns `true` if more data may follow the end of the target, see
+   /// [`crate::streaming::Partial`]
+   fn is_partial(&self) -> bool {
+      false
+   }
+
+   /// The text of `start..end`, used to report errors and track positions.
+   /// Binary data and splits inside a char are decoded lossily
+   fn text(&self, start: usize, end: usize) -> Cow<'_, str> {
+      match self.slice(start, end) {
+         Cow::Borrowed(bytes) => String::from_utf8_lossy(bytes),
+         Cow::Owned(bytes) => Cow::Owned(String::from_utf8_lossy(&bytes).into_owned())
+      }
+   }
+}
+
+/// Shared implementation of the text targets
+pub(crate) fn str_unit_len(target: &str, index: usize) -> usize {
+   target.get(index..)
+      .and_then(|rest| rest.chars().next())
+      .map(char::len_utf8)
+      .unwrap_or(0)
+}
+
+pub(crate) fn str_text(target: &str, start: usize, end: usize) -> Cow<'_, str> {
+   match target.get(start..end) {
+      Some(text) => Cow::Borrowed(text),
+      None => String::from_utf8_lossy(&target.as_bytes()[start..end])
+   }
+}
+
+impl Input for &str {
+   fn len(&self) -> usize { str::len(self) }
+
+   fn chunk(&self, index: usize) -> &[u8] { &str::as_bytes(self)[index..] }
+
+   fn unit_len(&self, index: usize) -> usize { str_unit_len(self, index) }
+
+   fn is_boundary(&self, index: usize) -> bool { self.is_char_boundary(index) }
+
+   fn is_text(&self) -> bool { true }
+
+   fn text(&self, start: usize, end: usize) -> Cow<'_, str> { str_text(self, start, end) }
+}
+
+impl Input for String {
+   fn len(&self) -> usize { str::len(self) }
+
+   fn chunk(&self, index: usize) -> &[u8] { &str::as_bytes(self)[index..] }
+
+   fn unit_len(&self, index: usize) -> usize { str_unit_len(self, index) }
+
+   fn is_boundary(&self, index: usize) -> bool { self.is_char_boundary(index) }
+
+   fn is_text(&self) -> bool { true }
+
+   fn text(&self, start: usize, end: usize) -> Cow<'_, str> { str_text(self, start, end) }
+}
+
+impl Input for Rc<str> {
+   fn len(&self) -> usize { str::len(self) }
+
+   fn chunk(&self, index: usize) -> &[u8] { &str::as_bytes(self)[index..] }
+
+   fn unit_len(&self, index: usize) -> usize { str_unit_len(self, index) }
+
+   fn is_boundary(&self, index: usize) -> bool { self.is_char_boundary(index) }
+
+   fn is_text(&self) -> bool { true }
+
+   fn text(&self, start: usize, end: usize) -> Cow<'_, str> { str_text(self, start, end) }
+}
+
+impl Input for &[u8] {
+   fn len(&self) -> usize { <[u8]>::len(self) }
+
+   fn chunk(&self, index: usize) -> &[u8] { &self[index..] }
+
+   fn unit_len(&self, index: usize) -> usize {
+      if index < self.len() { 1 } else { 0 }
+   }
+}
+
+impl Input for Vec<u8> {
+   fn len(&self) -> usize { <[u8]>::len(self) }
+
+   fn chunk(&self, index: usize) -> &[u8] { &self[index..] }
+
+   fn unit_len(&self, index: usize) -> usize {
+      if index < self.len() { 1 } else { 0 }
+   }
+}
+
+/// # FromSlice
+/// The result types of the parsers returning the part of the target they
+/// matched. Owned results (`String`, `Vec<u8>`) are copied out of any
+/// target, borrowed results (`&str`, `&[u8]`) are slices of a borrowed
+/// target of the same type and save an allocation per match
+pub trait FromSlice<T> {
+   /// Build the result from the bytes `start..end` of `target`
+   fn from_slice(target: &T, start: usize, end: usize) -> Self;
+}
+
+impl<T: Input> FromSlice<T> for String {
+   fn from_slice(target: &T, start: usize, end: usize) -> Self {
+      target.text(start, end).into_owned()
+   }
+}
+
+impl<T: Input> FromSlice<T> for Vec<u8> {
+   fn from_slice(target: &T, start: usize, end: usize) -> Self {
+      target.slice(start, end).into_owned()
+   }
+}
+
+impl<'s> FromSlice<&'s str> for &'s str {
+   fn from_slice(target: &&'s str, start: usize, end: usize) -> Self {
+      let target: &'s str = target;
+      &target[start..end]
+   }
+}
+
+impl<'s> FromSlice<&'s [u8]> for &'s [u8] {
+   fn from_slice(target: &&'s [u8], start: usize, end: usize) -> Self {
+      let target: &'s [u8] = target;
+      &target[start..end]
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use std::rc::Rc;
+   use super::Input;
+
+   #[test]
+   fn text_inputs_agree() {
+      let owned = "añb".to_owned();
+      let shared: Rc<str> = Rc::from("añb");
+
+      assert_eq!(owned.unit_len(1), 2);
+      assert_eq!(shared.unit_len(1), 2);
+      assert_eq!(shared.unit_len(4), 0);
+      assert!(!owned.is_boundary(2));
+      assert_eq!(owned.text(0, 3), "añ");
+   }
+
+   #[test]
+   fn text_split_inside_char_is_lossy() {
+      assert_eq!("ñ".text(0, 1), "\u{fffd}");
+   }
+
+   #[test]
+   fn bytes_are_single_units() {
+      let bytes: &[u8] = "ñ".as_bytes();
+
+      assert_eq!(bytes.unit_len(0), 1);
+      assert!(bytes.is_boundary(1));
+      assert_eq!(bytes.unit_len(2), 0);
+   }
+}
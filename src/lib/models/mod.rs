@@ -2,4 +2,9 @@
 pub mod cardinality;
 pub mod state;
 pub mod parser_traits;
-pub mod parser;
\ No newline at end of file
+pub mod parser;
+pub mod parse_error;
+pub mod position;
+pub mod tracker;
+pub mod input;
+pub mod endian;
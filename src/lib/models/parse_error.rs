@@ -0,0 +1,379 @@
+use std::{error::Error, fmt};
+
+use crate::parsers::suggest;
+
+/// # ErrorKind
+/// The category of a [`ParseError`], used to branch on a failure without
+/// having to inspect its message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+   /// The parser reached the end of the target before it could match
+   UnexpectedEndOfInput,
+   /// The input at the error index did not match what the parser expected
+   Mismatch,
+   /// A user defined failure, usually raised while mapping a result
+   Custom(String),
+   /// The parser reached the end of a partial target, it needs at least
+   /// this many more bytes to decide
+   Incomplete(usize),
+   /// The parsed number does not fit in this many bits
+   Overflow(u32),
+}
+
+/// # ParseError
+/// Describes why and where a parser failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+   /// The category of the failure
+   pub kind: ErrorKind,
+   /// The name of the parser that failed (e.g. `"Str"`)
+   pub parser: String,
+   /// The byte offset in the target where the failure happened
+   pub index: usize,
+   /// The values the parser expected to find at `index`
+   pub expected: Vec<String>,
+   /// The value that was found at `index` instead, `None` at the end of input
+   pub found: Option<String>,
+   /// The error of an inner parser that caused this one
+   pub cause: Option<Box<ParseError>>,
+   /// The labels of the constructs being parsed when the error happened,
+   /// outermost first
+   pub context: Vec<String>,
+   /// Set once the parse has committed to a branch, stops `Choice`, `Many`
+   /// and `SepBy` from backtracking past the error
+   pub fatal: bool,
+   /// Close matches of the found input among the expected literals,
+   /// displayed as "did you mean ...?", see [`ParseError::suggestions`]
+   suggestions: Vec<String>,
+   /// The literals expected and the input found instead, only checked for
+   /// a typo when the suggestions are read: most mismatches are backtracked
+   /// past and never reported
+   near_misses: Vec<(String, String)>,
+}
+
+impl ParseError {
+   /// Create an error with the provided kind and no expectations
+   pub fn new(kind: ErrorKind, parser: &str, index: usize) -> Self {
+      Self {
+         kind,
+         parser: parser.to_owned(),
+         index,
+         expected: Vec::new(),
+         found: None,
+         cause: None,
+         context: Vec::new(),
+         fatal: false,
+         suggestions: Vec::new(),
+         near_misses: Vec::new()
+      }
+   }
+
+   /// Create an [`ErrorKind::UnexpectedEndOfInput`] error
+   pub fn end_of_input(parser: &str, index: usize, expected: String) -> Self {
+      let mut err = Self::new(ErrorKind::UnexpectedEndOfInput, parser, index);
+      err.expected.push(expected);
+      err
+   }
+
+   /// Create an [`ErrorKind::Mismatch`] error
+   pub fn mismatch(parser: &str, index: usize, expected: String, found: String) -> Self {
+      let mut err = Self::new(ErrorKind::Mismatch, parser, index);
+      err.expected.push(expected);
+      err.found = Some(found);
+      err
+   }
+
+   /// Create an [`ErrorKind::Incomplete`] error needing `needed` more bytes
+   pub fn incomplete(parser: &str, index: usize, expected: String, needed: usize) -> Self {
+      let mut err = Self::new(ErrorKind::Incomplete(needed), parser, index);
+      err.expected.push(expected);
+      err
+   }
+
+   /// Create an [`ErrorKind::Overflow`] error for a number not fitting in `bits` bits
+   pub fn overflow(parser: &str, index: usize, expected: String, found: String, bits: u32) -> Self {
+      let mut err = Self::new(ErrorKind::Overflow(bits), parser, index);
+      err.expected.push(expected);
+      err.found = Some(found);
+      err
+   }
+
+   /// Create an [`ErrorKind::Custom`] error with the provided message
+   pub fn custom(parser: &str, index: usize, message: String) -> Self {
+      Self::new(ErrorKind::Custom(message), parser, index)
+   }
+
+   /// Attach the error of an inner parser as the cause of this one
+   pub fn with_cause(mut self, cause: ParseError) -> Self {
+      self.cause = Some(Box::new(cause));
+      self
+   }
+
+   /// Add the suggestions of `other` that are not already part of this error
+   pub fn add_suggestions(&mut self, other: &[String]) {
+      for suggestion in other {
+         if !self.suggestions.contains(suggestion) {
+            self.suggestions.push(suggestion.clone());
+         }
+      }
+   }
+
+   /// Record that the literal `expected` was expected where `window` was
+   /// found, it is suggested if `window` starts with a typo of it
+   pub fn add_near_miss(&mut self, expected: &str, window: String) {
+      let near_miss = (expected.to_owned(), window);
+      if !self.near_misses.contains(&near_miss) {
+         self.near_misses.push(near_miss);
+      }
+   }
+
+   /// Add the suggestions and near misses of `other` that are not already
+   /// part of this error
+   pub(crate) fn merge_suggestions(&mut self, other: &ParseError) {
+      self.add_suggestions(&other.suggestions);
+      for (expected, window) in &other.near_misses {
+         self.add_near_miss(expected, window.clone());
+      }
+   }
+
+   /// The close matches of the found input among the expected literals:
+   /// the suggestions added and the near misses that look like a typo
+   pub fn suggestions(&self) -> Vec<String> {
+      let mut suggestions = self.suggestions.clone();
+      for (expected, window) in &self.near_misses {
+         if let Some(suggestion) = suggest(window, expected) {
+            if !suggestions.contains(&suggestion) {
+               suggestions.push(suggestion);
+            }
+         }
+      }
+
+      suggestions
+   }
+
+   /// The "did you mean ...?" hint built from the suggestions, `None` if
+   /// there are none
+   pub fn did_you_mean(&self) -> Option<String> {
+      let quoted: Vec<String> = self.suggestions()
+         .iter()
+         .map(|suggestion| format!("'{}'", suggestion))
+         .collect();
+
+      match quoted.as_slice() {
+         [] => None,
+         [single] => Some(format!("did you mean {}?", single)),
+         [rest @ .., last] => Some(format!("did you mean {} or {}?", rest.join(", "), last)),
+      }
+   }
+
+   /// Merge the errors that failed at the furthest index into a single error
+   /// raised by `parser` that expects any of their expected values and
+   /// suggests any of their suggestions. The merged error keeps their kind
+   /// if they share one, and is a mismatch expecting the messages of the
+   /// custom errors otherwise. It found the longest input any of them found.
+   /// Returns `None` if `errors` is empty
+   ///
+   /// # Examples
+   ///
+   /// ```
+   /// use parser_combinator::models::parse_error::ParseError;
+   ///
+   /// let plus = ParseError::mismatch("Str", 1, "'+'".to_owned(), "x".to_owned());
+   /// let minus = ParseError::mismatch("Str", 1, "'-'".to_owned(), "x".to_owned());
+   ///
+   /// let merged = ParseError::merge("Choice", vec![plus, minus]).unwrap();
+   /// assert_eq!(merged.to_string(), "Choice: unexpected input, expected one of '+', '-' but found 'x' at index 1");
+   /// ```
+   pub fn merge(parser: &str, errors: Vec<ParseError>) -> Option<Self> {
+      let index = errors.iter().map(|err| err.index).max()?;
+      let furthest: Vec<ParseError> = errors
+         .into_iter()
+         .filter(|err| err.index == index)
+         .collect();
+
+      let kind = match furthest.first() {
+         Some(first) if furthest.iter().all(|err| err.kind == first.kind) => first.kind.clone(),
+         _ => ErrorKind::Mismatch
+      };
+
+      let mut merged = Self::new(kind, parser, index);
+      for err in furthest {
+         merged.merge_suggestions(&err);
+
+         // the message of a custom error merged into a mismatch is what it expected
+         let message = match err.kind {
+            ErrorKind::Custom(message) if merged.kind == ErrorKind::Mismatch => Some(message),
+            _ => None
+         };
+
+         for expected in err.expected.into_iter().chain(message) {
+            if !merged.expected.contains(&expected) {
+               merged.expected.push(expected);
+            }
+         }
+
+         // alternatives of different lengths look at different amounts of input
+         let longer = match (&merged.found, &err.found) {
+            (Some(current), Some(found)) => found.chars().count() > current.chars().count(),
+            (None, found) => found.is_some(),
+            (Some(_), None) => false
+         };
+         if longer {
+            merged.found = err.found;
+         }
+      }
+
+      Some(merged)
+   }
+
+   /// Returns `true` if the parser failed because the target ran out
+   pub fn is_end_of_input(&self) -> bool {
+      self.kind == ErrorKind::UnexpectedEndOfInput
+   }
+
+   /// Returns `true` if the parser needs more of a partial target
+   pub fn is_incomplete(&self) -> bool {
+      matches!(self.kind, ErrorKind::Incomplete(_))
+   }
+
+   /// Returns `true` if the parser failed because the input did not match
+   pub fn is_mismatch(&self) -> bool {
+      self.kind == ErrorKind::Mismatch
+   }
+
+   /// Returns `true` if the parsed number was too large for its type
+   pub fn is_overflow(&self) -> bool {
+      matches!(self.kind, ErrorKind::Overflow(_))
+   }
+}
+
+impl fmt::Display for ParseError {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      if !self.context.is_empty() {
+         write!(f, "while parsing {}: ", self.context.join(" > "))?;
+      }
+
+      write!(f, "{}: ", self.parser)?;
+
+      match &self.kind {
+         ErrorKind::UnexpectedEndOfInput => write!(f, "unexpected end of input")?,
+         ErrorKind::Mismatch => write!(f, "unexpected input")?,
+         ErrorKind::Custom(message) => write!(f, "{}", message)?,
+         ErrorKind::Incomplete(needed) => write!(f, "incomplete input, {} more bytes needed", needed)?,
+         ErrorKind::Overflow(bits) => write!(f, "number does not fit in {} bits", bits)?,
+      }
+
+      match self.expected.as_slice() {
+         [] => {},
+         [single] => write!(f, ", expected {}", single)?,
+         many => write!(f, ", expected one of {}", many.join(", "))?,
+      }
+
+      if let Some(found) = &self.found {
+         write!(f, " but found '{}'", found)?;
+      }
+
+      write!(f, " at index {}", self.index)?;
+
+      if let Some(hint) = self.did_you_mean() {
+         write!(f, ", {}", hint)?;
+      }
+
+      Ok(())
+   }
+}
+
+impl Error for ParseError {
+   fn source(&self) -> Option<&(dyn Error + 'static)> {
+      self.cause.as_deref().map(|cause| cause as &(dyn Error + 'static))
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn mismatch_display() {
+      let err = ParseError::mismatch("Str", 3, "'cat'".to_owned(), "dog".to_owned());
+      assert!(err.is_mismatch());
+      assert_eq!(err.to_string(), "Str: unexpected input, expected 'cat' but found 'dog' at index 3");
+   }
+
+   #[test]
+   fn end_of_input_display() {
+      let err = ParseError::end_of_input("Digits", 5, "digits".to_owned());
+      assert!(err.is_end_of_input());
+      assert_eq!(err.to_string(), "Digits: unexpected end of input, expected digits at index 5");
+   }
+
+   #[test]
+   fn merge_keeps_furthest() {
+      let near = ParseError::mismatch("Str", 0, "'a'".to_owned(), "x".to_owned());
+      let far = ParseError::end_of_input("Str", 2, "'abc'".to_owned());
+      let far_dup = ParseError::end_of_input("Str", 2, "'abc'".to_owned());
+
+      let merged = ParseError::merge("Choice", vec![near, far, far_dup]).unwrap();
+      assert!(merged.is_end_of_input());
+      assert_eq!(merged.index, 2);
+      assert_eq!(merged.expected, vec!["'abc'"]);
+      assert!(ParseError::merge("Choice", Vec::new()).is_none());
+   }
+
+   #[test]
+   fn merge_keeps_custom_messages() {
+      let port = ParseError::custom("Port", 3, "port out of range".to_owned());
+      let same = ParseError::custom("Port", 3, "port out of range".to_owned());
+
+      let merged = ParseError::merge("Choice", vec![port.clone(), same]).unwrap();
+      assert_eq!(merged.kind, ErrorKind::Custom("port out of range".to_owned()));
+
+      let name = ParseError::mismatch("Str", 3, "'localhost'".to_owned(), "9".to_owned());
+      let merged = ParseError::merge("Choice", vec![name, port]).unwrap();
+      assert!(merged.is_mismatch());
+      assert_eq!(merged.expected, vec!["'localhost'", "port out of range"]);
+   }
+
+   #[test]
+   fn merge_keeps_longest_found() {
+      let short = ParseError::mismatch("Str", 0, "'if'".to_owned(), "in".to_owned());
+      let long = ParseError::mismatch("Str", 0, "'import'".to_owned(), "insert".to_owned());
+
+      let merged = ParseError::merge("Choice", vec![short, long]).unwrap();
+      assert_eq!(merged.found.as_deref(), Some("insert"));
+      assert_eq!(merged.to_string(), "Choice: unexpected input, expected one of 'if', 'import' but found 'insert' at index 0");
+   }
+
+   #[test]
+   fn near_misses_are_merged() {
+      let mut keyword = ParseError::mismatch("Str", 0, "'lambda'".to_owned(), "lamda ".to_owned());
+      keyword.add_near_miss("lambda", "lamda (x)".to_owned());
+      let mut paren = ParseError::mismatch("Str", 0, "'('".to_owned(), "l".to_owned());
+      paren.add_near_miss("(", "l".to_owned());
+
+      assert!(paren.suggestions().is_empty());
+
+      let merged = ParseError::merge("Choice", vec![paren, keyword]).unwrap();
+      assert_eq!(merged.suggestions(), vec!["lambda"]);
+   }
+
+   #[test]
+   fn did_you_mean_display() {
+      let mut err = ParseError::mismatch("Str", 0, "'lambda'".to_owned(), "lamda".to_owned());
+      err.add_suggestions(&["lambda".to_owned()]);
+      assert_eq!(err.to_string(), "Str: unexpected input, expected 'lambda' but found 'lamda' at index 0, did you mean 'lambda'?");
+
+      err.add_suggestions(&["lambda".to_owned(), "lambdas".to_owned(), "lamb".to_owned()]);
+      assert_eq!(err.did_you_mean().unwrap(), "did you mean 'lambda', 'lambdas' or 'lamb'?");
+   }
+
+   #[test]
+   fn cause_is_source() {
+      let inner = ParseError::end_of_input("Str", 2, "'b'".to_owned());
+      let outer = ParseError::custom("ManyOne", 0, "no matches".to_owned())
+         .with_cause(inner.clone());
+
+      let source = outer.source().unwrap().downcast_ref::<ParseError>().unwrap();
+      assert_eq!(source, &inner);
+   }
+}
@@ -1,14 +1,17 @@
 use std::fmt;
 
-use super::{state::State, parser_traits::Parse};
+use super::{state::State, parser_traits::Parse, parse_error::ParseError};
+
+/// The boxed closure run by a [`Parser`] to transform a state
+pub type TransformerFn<'a, R1, R2, T, E = ParseError> = Box<dyn Fn(State<R1,T,E>) -> State<R2,T,E> + 'a>;
 
 /// # Parser: 
 /// a generic parser container that implements the parser trait
-pub struct Parser<'a, R1, R2, T> {
-   pub transformer_fn: Box<dyn Fn(State<R1,T>) -> State<R2,T> + 'a>,
+pub struct Parser<'a, R1, R2, T, E = ParseError> {
+   pub transformer_fn: TransformerFn<'a, R1, R2, T, E>,
 }
 
-impl<'a, R1, R2, T> Parser<'a, R1, R2, T> {
+impl<'a, R1, R2, T, E> Parser<'a, R1, R2, T, E> {
    
    /// Instantiates a `Parser` struct
    /// 
@@ -19,35 +22,40 @@ impl<'a, R1, R2, T> Parser<'a, R1, R2, T> {
    /// use parser_combinator::models::parser::Parser;
    /// use parser_combinator::models::state::State;
    /// use parser_combinator::models::cardinality::Cardinality;
+   /// use parser_combinator::models::parse_error::ParseError;
    /// 
    /// Parser::new(Box::new(|state: State<String,String>| {
    ///    if *state.target == "cat" {
    ///       return State{ 
    ///          index: 3, 
    ///          target: state.target, 
+   ///          position: state.position.advance("cat"),
+   ///          tracker: state.tracker,
    ///          result: Some(Ok(Cardinality::One(String::from("cat"))))}
    ///    }
    /// 
    ///    return State{ 
    ///       index: state.index, 
    ///       target: state.target, 
-   ///       result: Some(Err(String::from("Unable to match string 'cat'")))};
+   ///       position: state.position,
+   ///       tracker: state.tracker,
+   ///       result: Some(Err(ParseError::custom("cat", state.index, String::from("Unable to match string 'cat'"))))};
    /// }));
    /// ```
-   pub fn new(transformer_fn: Box<dyn Fn(State<R1,T>) -> State<R2,T> + 'a>) -> Self {
+   pub fn new(transformer_fn: TransformerFn<'a, R1, R2, T, E>) -> Self {
       Self { transformer_fn }
    }
 }
 
-impl<'a, R1, R2, T> fmt::Debug for Parser<'a, R1, R2, T> {
+impl<'a, R1, R2, T, E> fmt::Debug for Parser<'a, R1, R2, T, E> {
    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
       f.debug_struct("Custom Parse").finish()
    }
 }
 
-impl<'a, R1, R2, T> Parse<R1, R2, T> for Parser<'a, R1, R2, T> {
+impl<'a, R1, R2, T, E> Parse<R1, R2, T, E> for Parser<'a, R1, R2, T, E> {
    /// Run the parsing the logic on a `State` instance
-   fn transform(&self, state: State<R1, T>) -> State<R2, T> {
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
       (self.transformer_fn)(state)
    }
 }
@@ -55,7 +63,7 @@ impl<'a, R1, R2, T> Parse<R1, R2, T> for Parser<'a, R1, R2, T> {
 
 #[cfg(test)]
 mod tests {
-    use crate::models::{state::State, cardinality::Cardinality};
+    use crate::models::{state::State, cardinality::Cardinality, parse_error::ParseError};
 
     use super::Parser;
 
@@ -67,32 +75,40 @@ mod tests {
                return State{ 
                   index: 3, 
                   target: state.target, 
+                  position: state.position.advance("cat"),
+                  tracker: state.tracker,
                   result: Some(Ok(Cardinality::One(String::from("cat"))))}
             }
 
             State{ 
                index: state.index, 
                target: state.target, 
-               result: Some(Err(String::from("Unable to match string 'cat'")))}
+               position: state.position,
+               tracker: state.tracker,
+               result: Some(Err(ParseError::custom("cat", state.index, String::from("Unable to match string 'cat'"))))}
          }));
    }
 
    #[test]
    fn test() {
-      use crate::models::{parser::Parser, state::State, cardinality::Cardinality};
+      use crate::models::{parser::Parser, state::State, cardinality::Cardinality, parse_error::ParseError};
                   
       Parser::new(Box::new(|state: State<String,String>| {
          if *state.target == "cat" {
             return State{ 
                index: 3, 
                target: state.target, 
+               position: state.position.advance("cat"),
+               tracker: state.tracker,
                result: Some(Ok(Cardinality::One(String::from("cat"))))}
          }
        
          State{ 
             index: state.index, 
             target: state.target, 
-            result: Some(Err(String::from("Unable to match string 'cat'")))}
+            position: state.position,
+            tracker: state.tracker,
+            result: Some(Err(ParseError::custom("cat", state.index, String::from("Unable to match string 'cat'"))))}
       }));
    }
 }
\ No newline at end of file
@@ -1,13 +1,111 @@
-use std::{rc::Rc, fmt::Debug};
-use super::state::State;
+use std::{rc::Rc, fmt::Debug, io, path::Path};
+use super::{parse_error::ParseError, state::State};
+use crate::file_input::FromFile;
 
 
 /// # Parse
-/// Trait used by the parsers to ensure they interoperate with each other
-pub trait Parse<R1,R2,T>: Debug {
-   fn transform(&self, state: State<R1, T>) -> State<R2, T>;
-   fn run(&self, target: T) -> State<R2, T> {
-      let initial_state = State{target: Rc::new(target), index: 0, result: None };
-      self.transform(initial_state)
+/// Trait used by the parsers to ensure they interoperate with each other.
+/// `E` is the error type of the results, see [`CombinatorError`]
+pub trait Parse<R1,R2,T,E = ParseError>: Debug {
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E>;
+
+   /// Run the parser on `target`. When it fails the error reported is the
+   /// farthest failure reached by any parser, even one that was backtracked
+   fn run(&self, target: T) -> State<R2, T, E>
+      where E: CombinatorError {
+      self.transform(State::new(Rc::new(target)))
+         .with_farthest_error()
+   }
+
+   /// Run the parser on the contents of the file at `path`, read into a
+   /// buffer. The target type picks how the file is parsed, see
+   /// [`crate::file_input::FileInput`] and [`crate::file_input::TextFileInput`]
+   fn run_file<P: AsRef<Path>>(&self, path: P) -> io::Result<State<R2, T, E>>
+      where T: FromFile, E: CombinatorError, Self: Sized {
+      Ok(self.run(T::open_file(path.as_ref())?))
+   }
+
+   /// Run the parser on the contents of the file at `path`, mapped in memory
+   /// so a large file is parsed with constant extra memory. Falls back to
+   /// [`Parse::run_file`]'s buffered read when the file can't be mapped or
+   /// the `mmap` feature is disabled
+   ///
+   /// # Safety
+   /// The file must not be modified or truncated while the returned state
+   /// is alive, see [`crate::file_input::FileInput::open_mapped`]
+   unsafe fn run_file_mapped<P: AsRef<Path>>(&self, path: P) -> io::Result<State<R2, T, E>>
+      where T: FromFile, E: CombinatorError, Self: Sized {
+      // SAFETY: forwarded to the caller
+      Ok(self.run(unsafe { T::open_file_mapped(path.as_ref())? }))
+   }
+}
+
+/// # CombinatorError
+/// Trait for the error types the combinators can work with. The built in
+/// parsers raise [`ParseError`]s which are converted through `From` (see
+/// [`crate::parsers::into_err_parser::IntoErr`]), user defined errors such as
+/// domain validation failures are passed through the combinators untouched
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::models::parse_error::ParseError;
+/// use parser_combinator::models::parser_traits::CombinatorError;
+///
+/// #[derive(Debug, Clone)]
+/// enum ConfigError {
+///    Syntax(ParseError),
+///    PortOutOfRange(u64)
+/// }
+///
+/// impl From<ParseError> for ConfigError {
+///    fn from(err: ParseError) -> Self { ConfigError::Syntax(err) }
+/// }
+///
+/// impl CombinatorError for ConfigError {
+///    fn as_parse_error(&self) -> Option<&ParseError> {
+///       match self {
+///          ConfigError::Syntax(err) => Some(err),
+///          _ => None
+///       }
+///    }
+///
+///    fn as_parse_error_mut(&mut self) -> Option<&mut ParseError> {
+///       match self {
+///          ConfigError::Syntax(err) => Some(err),
+///          _ => None
+///       }
+///    }
+/// }
+/// ```
+pub trait CombinatorError: From<ParseError> + Clone + Debug {
+   /// The built in error wrapped by this one, used by the combinators to
+   /// read its index and expected values. User defined failures return `None`
+   fn as_parse_error(&self) -> Option<&ParseError>;
+
+   /// Mutable access to the built in error wrapped by this one
+   fn as_parse_error_mut(&mut self) -> Option<&mut ParseError>;
+
+   /// Returns `true` if the error was marked as fatal by
+   /// [`crate::parsers::cut_parser::Cut`]
+   fn is_fatal(&self) -> bool {
+      self.as_parse_error().map(|err| err.fatal).unwrap_or(false)
+   }
+
+   /// Returns `true` if the error is [`crate::models::parse_error::ErrorKind::Incomplete`],
+   /// the combinators return it instead of trying other alternatives as the
+   /// rest of the input is not known yet
+   fn is_incomplete(&self) -> bool {
+      self.as_parse_error().map(|err| err.is_incomplete()).unwrap_or(false)
+   }
+}
+
+impl CombinatorError for ParseError {
+   fn as_parse_error(&self) -> Option<&ParseError> {
+      Some(self)
+   }
+
+   fn as_parse_error_mut(&mut self) -> Option<&mut ParseError> {
+      Some(self)
    }
 }
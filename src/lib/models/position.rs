@@ -0,0 +1,99 @@
+use std::fmt;
+
+/// # Position
+/// The human readable location of an index in a text target. Lines and
+/// columns both start at 1 and columns are counted in chars, not bytes.
+///
+/// Both `\n` and `\r\n` end a line, the `\r` of a `\r\n` pair does not
+/// count as a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+   pub line: usize,
+   pub column: usize
+}
+
+impl Position {
+   /// The position of the first char of a target
+   pub fn new() -> Self {
+      Self { line: 1, column: 1 }
+   }
+
+   /// Compute the position of `index` by scanning `target` from its start,
+   /// an index inside a char is the position of that char
+   pub fn from_index(target: &str, index: usize) -> Self {
+      let mut index = index.min(target.len());
+      while !target.is_char_boundary(index) {
+         index -= 1;
+      }
+
+      Self::new().advance(&target[..index])
+   }
+
+   /// Returns the position reached after consuming `consumed` from this one
+   ///
+   /// # Examples
+   ///
+   /// ```
+   /// use parser_combinator::models::position::Position;
+   ///
+   /// let position = Position::new().advance("ab\r\ncd");
+   /// assert_eq!(position, Position { line: 2, column: 3 });
+   /// ```
+   pub fn advance(self, consumed: &str) -> Self {
+      let mut position = self;
+
+      for c in consumed.chars() {
+         match c {
+            '\n' => {
+               position.line += 1;
+               position.column = 1;
+            },
+            '\r' => {},
+            _ => position.column += 1
+         }
+      }
+
+      position
+   }
+}
+
+impl Default for Position {
+   fn default() -> Self { Self::new() }
+}
+
+impl fmt::Display for Position {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      write!(f, "{}:{}", self.line, self.column)
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::Position;
+
+   #[test]
+   fn advance_single_line() {
+      let position = Position::new().advance("abc");
+      assert_eq!(position, Position { line: 1, column: 4 });
+   }
+
+   #[test]
+   fn advance_lf_and_crlf() {
+      let lf = Position::new().advance("a\nbc");
+      let crlf = Position::new().advance("a\r\nbc");
+      assert_eq!(lf, Position { line: 2, column: 3 });
+      assert_eq!(crlf, lf);
+   }
+
+   #[test]
+   fn advance_counts_chars() {
+      let position = Position::new().advance("héllo");
+      assert_eq!(position.column, 6);
+   }
+
+   #[test]
+   fn from_index() {
+      let position = Position::from_index("ab\ncd", 4);
+      assert_eq!(position.to_string(), "2:2");
+   }
+}
@@ -1,30 +1,114 @@
 use std::{rc::Rc, fmt::Debug};
-use super::cardinality::Cardinality;
+use super::{
+   cardinality::Cardinality,
+   parse_error::ParseError,
+   parser_traits::CombinatorError,
+   position::Position,
+   tracker::Tracker
+};
 
-pub type  ParserResult<R> = Option<Result<Cardinality<R>, String>>;
+pub type  ParserResult<R, E = ParseError> = Option<Result<Cardinality<R>, E>>;
 
 /// # State
-/// Represents the state returned from the parser 
+/// Represents the state returned from the parser
 #[derive(Clone)]
-pub struct State<R, T> {
+pub struct State<R, T, E = ParseError> {
    /// The index where the parser will start from
    pub index: usize,
    /// The target data that will be parsed
    pub target: Rc<T>,
-   pub result: ParserResult<R>
+   /// The line and column matching `index` in a text target. It is not
+   /// advanced on binary targets, nor on token targets, see
+   /// [`crate::token_parsers::Token`]
+   pub position: Position,
+   pub result: ParserResult<R, E>,
+   /// The bookkeeping shared by every state of the current run
+   pub tracker: Rc<Tracker<E>>
 }
 
-impl<R: Debug, T: Debug> State<R, T> {
+impl<R, T, E> State<R, T, E> {
+   /// Create the initial state for parsing `target`
+   pub fn new(target: Rc<T>) -> Self {
+      Self { index: 0, target, position: Position::new(), result: None, tracker: Rc::new(Tracker::new()) }
+   }
+
+   /// Create a state at the same index and position with an empty result,
+   /// ready to be passed to the next parser
+   pub fn reset<R2>(&self) -> State<R2, T, E> {
+      State {
+         index: self.index,
+         target: Rc::clone(&self.target),
+         position: self.position,
+         result: None,
+         tracker: Rc::clone(&self.tracker)
+      }
+   }
+
+   /// Check if the result is an error type. Returns `false` if
+   /// the result is `Ok()` or `None`
+   pub fn is_error(&self) -> bool {
+      self.result
+         .as_ref().map(|r| r.is_err())
+         .unwrap_or(false)
+   }
+}
+
+impl<R, T, E: CombinatorError> State<R, T, E> {
+   /// Replace the error of a failed state with the farthest failure
+   /// recorded during the run, if it happened further in the target.
+   /// The farthest failure keeps the context and `fatal` flag of the
+   /// replaced error, as it was recorded before any `Label` annotated it.
+   /// User defined and incomplete errors are never replaced
+   pub fn with_farthest_error(mut self) -> Self {
+      let (err_index, context, fatal) = match self.result.as_ref() {
+         Some(Err(err)) if err.is_incomplete() => return self,
+         Some(Err(err)) => match err.as_parse_error() {
+            Some(err) => (err.index, err.context.clone(), err.fatal),
+            None => return self
+         },
+         _ => return self
+      };
+
+      if let Some(mut failure) = self.tracker.farthest() {
+         let farthest_index = failure.error.as_parse_error().map(|err| err.index);
+
+         if let Some(index) = farthest_index.filter(|index| *index > err_index) {
+            if let Some(err) = failure.error.as_parse_error_mut() {
+               err.context = context;
+               err.fatal = fatal;
+            }
+
+            self.index = index;
+            self.position = failure.position;
+            self.result = Some(Err(failure.error));
+         }
+      }
+
+      self
+   }
+}
+
+impl<R: Debug, T: Debug, E: CombinatorError> State<R, T, E> {
    // Create an error from the existing state
-   pub fn new_err<R2>(self, err: String) -> State<R2, T> {
+   pub fn new_err<R2>(self, err: E) -> State<R2, T, E> {
+      self.tracker.record(self.position, &err);
+      self.forward_err(err)
+   }
+
+   /// Create an error from the existing state without recording it as a new
+   /// failure, used by combinators to report errors derived from the ones
+   /// their inner parsers already raised
+   pub fn forward_err<R2>(self, err: E) -> State<R2, T, E> {
       State {
          index : self.index,
          target: self.target,
-         result: Some(Err(err))
+         position: self.position,
+         result: Some(Err(err)),
+         tracker: self.tracker
       }
    }
 
-   pub fn from_err_state<R2>(state: State<R2,T>) -> Self {
+   pub fn from_err_state<R2>(state: State<R2, T, E>) -> Self {
       if state.result.is_none() {
          panic!("from_err_state: result can't be none")
       }
@@ -33,28 +117,27 @@ impl<R: Debug, T: Debug> State<R, T> {
          panic!("from_err_state: result can't be ok")
       }
 
-      let err_res: Result<Cardinality<R>, String> = match  state.result.unwrap() {
+      let err_res: Result<Cardinality<R>, E> = match  state.result.unwrap() {
          Err(err) => Err(err),
          _ => panic!("from_err_state: result must be err")
       };
 
-      Self { index: state.index, target: Rc::clone(&state.target), result: Some(err_res) }
-   }
-
-   /// Check if the result is an error type. Returns `false` if 
-   /// the result is `Ok()` or `None`
-   pub fn is_error(&self) -> bool {
-      self.result
-         .as_ref().map(|r| r.is_err())
-         .unwrap_or(false)
+      Self {
+         index: state.index,
+         target: Rc::clone(&state.target),
+         position: state.position,
+         result: Some(err_res),
+         tracker: state.tracker
+      }
    }
 }
 
-impl<R: Debug, T: Debug> Debug for State<R, T> {
+impl<R: Debug, T: Debug, E: Debug> Debug for State<R, T, E> {
    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
       f.debug_struct("State")
          .field("index", &self.index)
+         .field("position", &self.position)
          .field("target", &self.target)
          .field("result", &self.result).finish()
    }
-}
\ No newline at end of file
+}
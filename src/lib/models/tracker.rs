@@ -0,0 +1,125 @@
+use std::cell::RefCell;
+
+use super::{parse_error::ParseError, parser_traits::CombinatorError, position::Position};
+
+/// # Failure
+/// An error recorded by the [`Tracker`] with the position it was raised at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<E = ParseError> {
+   pub position: Position,
+   pub error: E
+}
+
+/// # Tracker
+/// Bookkeeping shared by every state of a single run. It records the
+/// farthest failure any parser reached, even when a combinator such as
+/// `Choice` or `Many` backtracks and drops the error, and the errors
+/// that were recovered from
+#[derive(Debug)]
+pub struct Tracker<E = ParseError> {
+   farthest: RefCell<Option<Failure<E>>>,
+   errors: RefCell<Vec<E>>
+}
+
+impl<E> Tracker<E> {
+   pub fn new() -> Self {
+      Self { farthest: RefCell::new(None), errors: RefCell::new(Vec::new()) }
+   }
+
+   /// Collect an error that a parser recovered from
+   pub fn push_error(&self, err: E) {
+      self.errors.borrow_mut().push(err);
+   }
+}
+
+impl<E> Default for Tracker<E> {
+   fn default() -> Self { Self::new() }
+}
+
+impl<E: CombinatorError> Tracker<E> {
+   /// Record a failure, keeping it if it is farther than the current one.
+   /// The expected values and suggestions of failures at the same index
+   /// are merged.
+   /// User defined errors have no index and are not recorded
+   pub fn record(&self, position: Position, err: &E) {
+      let parse_err = match err.as_parse_error() {
+         Some(parse_err) => parse_err,
+         None => return
+      };
+
+      let mut farthest = self.farthest.borrow_mut();
+      let current_index = farthest
+         .as_ref()
+         .and_then(|current| current.error.as_parse_error())
+         .map(|current| current.index);
+
+      match current_index {
+         Some(index) if index > parse_err.index => {},
+         Some(index) if index == parse_err.index => {
+            let current = farthest
+               .as_mut()
+               .and_then(|current| current.error.as_parse_error_mut())
+               .unwrap();
+
+            for expected in &parse_err.expected {
+               if !current.expected.contains(expected) {
+                  current.expected.push(expected.clone());
+               }
+            }
+
+            current.merge_suggestions(parse_err);
+         },
+         _ => *farthest = Some(Failure { position, error: err.clone() })
+      }
+   }
+
+   /// The farthest failure recorded so far
+   pub fn farthest(&self) -> Option<Failure<E>> {
+      self.farthest.borrow().clone()
+   }
+
+   /// The errors recovered from so far, in the order they happened
+   pub fn errors(&self) -> Vec<E> {
+      self.errors.borrow().clone()
+   }
+
+   /// Record the failures and errors of a tracker used by parsers raising
+   /// the built in [`ParseError`], converting them to `E`
+   pub fn absorb(&self, other: &Tracker<ParseError>) {
+      if let Some(failure) = other.farthest() {
+         self.record(failure.position, &E::from(failure.error));
+      }
+
+      for err in other.errors() {
+         self.push_error(E::from(err));
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::{parse_error::ParseError, position::Position};
+   use super::Tracker;
+
+   #[test]
+   fn keeps_farthest() {
+      let tracker = Tracker::new();
+      let far = ParseError::mismatch("Str", 4, "'b'".to_owned(), "c".to_owned());
+      let near = ParseError::mismatch("Str", 1, "'a'".to_owned(), "c".to_owned());
+
+      tracker.record(Position { line: 1, column: 5 }, &far);
+      tracker.record(Position::new(), &near);
+
+      assert_eq!(tracker.farthest().unwrap().error, far);
+   }
+
+   #[test]
+   fn merges_same_index() {
+      let tracker = Tracker::new();
+      tracker.record(Position::new(), &ParseError::mismatch("Str", 0, "'a'".to_owned(), "c".to_owned()));
+      tracker.record(Position::new(), &ParseError::mismatch("Str", 0, "'b'".to_owned(), "c".to_owned()));
+      tracker.record(Position::new(), &ParseError::mismatch("Str", 0, "'a'".to_owned(), "c".to_owned()));
+
+      assert_eq!(tracker.farthest().unwrap().error.expected, vec!["'a'", "'b'"]);
+   }
+}
@@ -4,16 +4,16 @@ use crate::models::{state::{ParserResult, State}, parser::Parser, parser_traits:
 /// Maps the result of a parser that implements the [`Parse`] trait and creates a
 /// generic [`Parser`] that will run the previous parser and return a state with 
 /// the mapped result
-pub fn map_result<'a, R1, R2, T, S, F, I>(parser: I, map_fn: F) -> Parser<'a, R1, S, T>
-   where F: Fn(ParserResult<R2>) -> ParserResult<S> + 'a,
-         I: Parse<R1,R2,T> + 'a,
-         R1: 'a, R2: 'a, T: 'a, S: 'a {
+pub fn map_result<'a, R1, R2, T, S, F, I, E>(parser: I, map_fn: F) -> Parser<'a, R1, S, T, E>
+   where F: Fn(ParserResult<R2, E>) -> ParserResult<S, E> + 'a,
+         I: Parse<R1,R2,T,E> + 'a,
+         R1: 'a, R2: 'a, T: 'a, S: 'a, E: 'a {
 
-   let transformer = move |state: State<R1,T>| {
+   let transformer = move |state: State<R1,T,E>| {
       let next = parser.transform(state);
 
       let result = map_fn(next.result);
-      State{ index: next.index, target: next.target, result }
+      State{ index: next.index, target: next.target, position: next.position, result, tracker: next.tracker }
    };
 
    Parser::new(Box::new(transformer))
@@ -23,12 +23,12 @@ pub fn map_result<'a, R1, R2, T, S, F, I>(parser: I, map_fn: F) -> Parser<'a, R1
 /// Chains to parsers to each other through a closure that takes in the result 
 /// of the first parser and returns a struct that implements the [`Parse`] trait.
 /// Returns a new generic [`Parser`] that executes the parsers after each other 
-pub fn chain_parser<'a,I,F,R1,R2,T, S>(parser: I, chain_fn: F) -> Box<dyn Parse<R1, S, T> + 'a>
-   where F: Fn(& ParserResult<R2>) -> Box<dyn Parse<R2, S, T> + 'a> + 'a,
-         I: Parse<R1,R2,T> + 'a,
-         R1: 'a, R2: 'a, T: 'a, S: 'a {
+pub fn chain_parser<'a,I,F,R1,R2,T, S, E>(parser: I, chain_fn: F) -> Box<dyn Parse<R1, S, T, E> + 'a>
+   where F: Fn(& ParserResult<R2, E>) -> Box<dyn Parse<R2, S, T, E> + 'a> + 'a,
+         I: Parse<R1,R2,T,E> + 'a,
+         R1: 'a, R2: 'a, T: 'a, S: 'a, E: 'a {
 
-   let transformer = move |state: State<R1, T>| {
+   let transformer = move |state: State<R1, T, E>| {
       let next = parser.transform(state);
       let next_parser = chain_fn(&next.result);
 
@@ -0,0 +1,130 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::models::{parser_traits::Parse, state::State, parse_error::ParseError, input::{Input, FromSlice}};
+use crate::models::cardinality::Cardinality::One;
+
+use super::{inside_char, is_cut_char, next_unit, out_of_input, take_chars_while, window};
+
+/// # Alphanumeric
+/// Parse at least one ASCII letter or digit, or at least one Unicode
+/// letter or digit (e.g. `é`, `λ`, `字` or `٣`) once built with
+/// [`Alphanumeric::unicode`]
+///
+/// ### Returns:
+/// The letters and digits as a `String`, or as a `&str` borrowed from a
+/// `&str` target when built with [`Alphanumeric::borrowed`]
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::parsers::alphanumeric_parser::Alphanumeric;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let result = Alphanumeric::new().unicode().run("größe2 = 2");
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), "größe2");
+/// assert_eq!(result.index, 8);
+/// ```
+#[derive(Clone)]
+pub struct Alphanumeric<O = String> {
+   is_match: fn(char) -> bool,
+   _o: PhantomData<O>
+}
+
+impl Alphanumeric {
+   pub fn new() -> Self {
+      Alphanumeric { is_match: |c| c.is_ascii_alphanumeric(), _o: PhantomData }
+   }
+}
+
+impl Alphanumeric<&str> {
+   /// Instantiate an [`Alphanumeric`] parser returning a slice of the target
+   /// instead of allocating a `String` for every match
+   pub fn borrowed() -> Self {
+      Alphanumeric { is_match: |c| c.is_ascii_alphanumeric(), _o: PhantomData }
+   }
+}
+
+impl<O> Alphanumeric<O> {
+   /// Match the letters and digits of every script instead of ASCII ones only
+   pub fn unicode(self) -> Self {
+      Alphanumeric { is_match: char::is_alphanumeric, _o: PhantomData }
+   }
+}
+
+impl Default for Alphanumeric {
+   fn default() -> Self { Self::new() }
+}
+
+impl<O> fmt::Debug for Alphanumeric<O> {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      f.debug_struct("Alphanumeric").finish()
+   }
+}
+
+impl<T: Input, O: FromSlice<T> + fmt::Debug> Parse<O,O,T> for Alphanumeric<O> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {
+      let contains_error = state.is_error();
+
+      if contains_error {
+         return state;
+      }
+
+      if let Some(err) = inside_char(&*state.target, "Alphanumeric", state.index, "letters or digits".to_owned()) {
+         return state.new_err(err);
+      }
+
+      if state.index >= state.target.len() {
+         let err = out_of_input(&*state.target, "Alphanumeric", state.index, "letters or digits".to_owned(), 1);
+         return state.new_err(err);
+      }
+
+      let match_end = take_chars_while(&*state.target, state.index, self.is_match);
+
+      // the match could continue in the data that has not arrived yet,
+      // possibly with the rest of a char cut by the end of the target
+      let rest = window(&*state.target, match_end, 4);
+      if state.target.is_partial() && (rest.is_empty() || is_cut_char(&rest)) {
+         let err = ParseError::incomplete("Alphanumeric", state.index, "letters or digits".to_owned(), 1);
+         return state.new_err(err);
+      }
+
+      if match_end == state.index {
+         let found = next_unit(&*state.target, state.index);
+         let err = ParseError::mismatch("Alphanumeric", state.index, "letters or digits".to_owned(), found);
+         return state.new_err(err);
+      }
+
+      let matched = state.target.text(state.index, match_end);
+      State { 
+         index: match_end,
+         position: state.position.advance(&matched),
+         target: state.target.clone(), // TODO: Work on clone 
+         result: Some(Ok(One(O::from_slice(&*state.target, state.index, match_end)))),
+         tracker: state.tracker
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use super::Alphanumeric;
+
+   #[test]
+   fn alphanumeric_ascii_run() {
+      let res = Alphanumeric::new().run("x86_64");
+      assert_eq!(res.result.unwrap().unwrap().unwrap_one(), "x86");
+      assert_eq!(res.index, 3);
+
+      assert!(Alphanumeric::new().run("été").result.unwrap().unwrap_err().is_mismatch());
+   }
+
+   #[test]
+   fn alphanumeric_unicode_run() {
+      let res = Alphanumeric::borrowed().unicode().run("変数٣ = 1");
+      assert_eq!(res.result.unwrap().unwrap().unwrap_one(), "変数٣");
+      assert_eq!(res.index, 8);
+   }
+}
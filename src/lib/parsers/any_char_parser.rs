@@ -0,0 +1,109 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::byte_parsers::escape;
+use crate::models::{parser_traits::Parse, state::State, parse_error::ParseError, input::{Input, FromSlice}};
+use crate::models::cardinality::Cardinality::One;
+
+use super::{char_at, inside_char, is_cut_char, out_of_input, window};
+
+/// # AnyChar:
+/// Parse a single Unicode scalar value, whatever its length in bytes. On
+/// binary targets the char is decoded from UTF-8 and invalid bytes fail
+///
+/// ### Returns:
+/// The char as a `String`, or as a `&str` borrowed from a `&str` target
+/// when built with [`AnyChar::borrowed`]
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::parsers::any_char_parser::AnyChar;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let result = AnyChar::new().run("字x");
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), "字");
+/// assert_eq!(result.index, 3);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AnyChar<O = String> {
+   _o: PhantomData<O>
+}
+
+impl AnyChar {
+   pub fn new() -> Self {
+      Self { _o: PhantomData }
+   }
+}
+
+impl AnyChar<&str> {
+   /// Instantiate an [`AnyChar`] parser returning a slice of the target
+   pub fn borrowed() -> Self {
+      Self { _o: PhantomData }
+   }
+}
+
+impl<T: Input, O: FromSlice<T> + Debug> Parse<O, O, T> for AnyChar<O> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      let target = Rc::clone(&state.target);
+      let expected = "any char".to_owned();
+
+      if let Some(err) = inside_char(&*target, "AnyChar", state.index, expected.clone()) {
+         return state.new_err(err);
+      }
+
+      match char_at(&*target, state.index) {
+         Some(c) => {
+            let end = state.index + c.len_utf8();
+            State {
+               index: end,
+               position: state.position.advance(&target.text(state.index, end)),
+               result: Some(Ok(One(O::from_slice(&*target, state.index, end)))),
+               target,
+               tracker: state.tracker
+            }
+         },
+         None => {
+            let sliced_target = window(&*target, state.index, 4);
+
+            // the target ends before the char, or in the middle of it
+            let err = if sliced_target.is_empty() || is_cut_char(&sliced_target) {
+               out_of_input(&*target, "AnyChar", state.index, expected, 1)
+            } else {
+               ParseError::mismatch("AnyChar", state.index, expected, escape(&sliced_target[..1]))
+            };
+            state.new_err(err)
+         }
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use super::AnyChar;
+
+   #[test]
+   fn any_char_decodes_bytes() {
+      let target: &[u8] = "ñ".as_bytes();
+      let result = AnyChar::new().run(target);
+
+      assert_eq!(result.result.unwrap().unwrap().unwrap_one(), "ñ");
+      assert_eq!(result.index, 2);
+   }
+
+   #[test]
+   fn any_char_rejects_invalid_bytes() {
+      let target: &[u8] = &[0xff, 0x61];
+      let err = AnyChar::new().run(target).result.unwrap().unwrap_err();
+
+      assert!(err.is_mismatch());
+      assert_eq!(err.found.as_deref(), Some("\\xff"));
+   }
+}
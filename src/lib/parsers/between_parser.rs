@@ -1,7 +1,8 @@
 use std::fmt::{Debug, self};
 
 use crate::models::parser::Parser;
-use crate::models::parser_traits::Parse;
+use crate::models::parse_error::ParseError;
+use crate::models::parser_traits::{Parse, CombinatorError};
 use crate::models::state::{ State, ParserResult };
 use crate::parser_helpers::map_result;
 use crate::models::cardinality::Cardinality;
@@ -14,19 +15,19 @@ use crate::collection_parsers::sequence_of_parser::SequenceOf;
 /// The result of the middle parser (value) or the error returned by any of the
 /// parsers (left, value, right)
 #[derive(Debug)]
-pub struct Between<'a,R1,R2,T> {
-   parser: Parser<'a,R1,R2,T>
+pub struct Between<'a,R1,R2,T,E = ParseError> {
+   parser: Parser<'a,R1,R2,T,E>
 }
 
-impl<'a, R1,R2,T> Between<'a,R1,R2,T> 
-   where R2: 'a + Debug, R1: 'a + Debug, T: 'a + Debug {
+impl<'a, R1,R2,T,E> Between<'a,R1,R2,T,E> 
+   where R2: 'a + Debug, R1: 'a + Debug, T: 'a + Debug, E: 'a + CombinatorError {
    pub fn new(
-      left: Box<dyn Parse<R1,R2,T>>,
-      right: Box<dyn Parse<R1,R2,T>>,
-      value: Box<dyn Parse<R1,R2,T>>) -> Self {
+      left: Box<dyn Parse<R1,R2,T,E>>,
+      right: Box<dyn Parse<R1,R2,T,E>>,
+      value: Box<dyn Parse<R1,R2,T,E>>) -> Self {
 
          let parser =  SequenceOf::new(vec![left, value, right]);
-         let parser = map_result::<'a>(parser, |opt: ParserResult<R2>| {
+         let parser = map_result::<'a>(parser, |opt: ParserResult<R2, E>| {
             opt.map(move |res| {
                res.map(move|card| {
                   let mut res_vec = card.unwrap_many();
@@ -44,13 +45,11 @@ impl<'a, R1,R2,T> Between<'a,R1,R2,T>
 }
 
 
-impl<'a,R1,R2,T> Parse<R1,R2,T> for Between<'a,R1,R2,T>  
-   where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug {
+impl<'a,R1,R2,T,E> Parse<R1,R2,T,E> for Between<'a,R1,R2,T,E>  
+   where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug, E: fmt::Debug {
 
-   fn transform(&self, state: State<R1, T>) -> State<R2, T> {      
-      let res = self.parser.transform(state);
-      
-      res
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {      
+      self.parser.transform(state)
    }
 }
 
@@ -81,7 +80,9 @@ mod tests {
       let bet = Between::new(left, right, value);
       let result = bet.run("(Test)");
 
-      assert!(result.result.unwrap().is_err());
+      let err = result.result.unwrap().unwrap_err();
+      assert_eq!(err.parser, "Str");
+      assert_eq!(err.index, 1);
       assert_eq!(result.index, 1);       
    }
 }
\ No newline at end of file
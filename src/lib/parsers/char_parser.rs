@@ -0,0 +1,110 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::models::{parser_traits::Parse, state::State, parse_error::ParseError, input::{Input, FromSlice}};
+use crate::models::cardinality::Cardinality::One;
+
+use super::{inside_char, next_unit, out_of_input, window};
+
+/// # Char:
+/// Parse a specific Unicode char, on text targets as well as on UTF-8
+/// encoded binary targets
+///
+/// ### Returns:
+/// The char as a `String`, or as a `&str` borrowed from a `&str` target
+/// when built with [`Char::borrowed`]
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::parsers::char_parser::Char;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let result = Char::new('λ').run("λx");
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), "λ");
+/// assert_eq!(result.index, 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Char<O = String> {
+   pub to_match: char,
+   _o: PhantomData<O>
+}
+
+impl Char {
+   pub fn new(to_match: char) -> Self {
+      Self { to_match, _o: PhantomData }
+   }
+}
+
+impl Char<&str> {
+   /// Instantiate a [`Char`] parser returning a slice of the target
+   pub fn borrowed(to_match: char) -> Self {
+      Self { to_match, _o: PhantomData }
+   }
+}
+
+impl<T: Input, O: FromSlice<T> + Debug> Parse<O, O, T> for Char<O> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {
+      if state.is_error() {
+         return state;
+      }
+
+      let target = Rc::clone(&state.target);
+      let expected = format!("'{}'", self.to_match);
+
+      if let Some(err) = inside_char(&*target, "Char", state.index, expected.clone()) {
+         return state.new_err(err);
+      }
+
+      let mut encoded = [0; 4];
+      let to_match = self.to_match.encode_utf8(&mut encoded).as_bytes();
+      let sliced_target = window(&*target, state.index, to_match.len());
+
+      if *sliced_target == *to_match {
+         let end = state.index + to_match.len();
+         return State {
+            index: end,
+            position: state.position.advance(&target.text(state.index, end)),
+            result: Some(Ok(One(O::from_slice(&*target, state.index, end)))),
+            target: state.target,
+            tracker: state.tracker
+         };
+      }
+
+      // the target ends before the char, or in the middle of it
+      if to_match.starts_with(&sliced_target) {
+         let needed = to_match.len() - sliced_target.len();
+         let err = out_of_input(&*target, "Char", state.index, expected, needed);
+         return state.new_err(err);
+      }
+
+      let found = next_unit(&*target, state.index);
+      let err = ParseError::mismatch("Char", state.index, expected, found);
+      state.new_err(err)
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::models::parser_traits::Parse;
+   use crate::streaming::Partial;
+   use super::Char;
+
+   #[test]
+   fn char_mismatch_reports_char() {
+      let err = Char::new('ñ').run("字").result.unwrap().unwrap_err();
+
+      assert!(err.is_mismatch());
+      assert_eq!(err.found.as_deref(), Some("字"));
+   }
+
+   #[test]
+   fn char_cut_by_end_of_input() {
+      let target: &[u8] = &"ñ".as_bytes()[..1];
+
+      assert!(Char::new('ñ').run(target).result.unwrap().unwrap_err().is_end_of_input());
+      assert!(Char::new('ñ').run(Partial::new(target, false)).result.unwrap().unwrap_err().is_incomplete());
+   }
+}
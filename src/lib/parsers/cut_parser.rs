@@ -0,0 +1,125 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::models::parse_error::ParseError;
+use crate::models::parser_traits::{Parse, CombinatorError};
+use crate::models::state::State;
+
+/// # Cut:
+/// Commits the parse to the current branch: if `parser` fails its error is
+/// marked as fatal, so an enclosing `Choice` will not try its other
+/// alternatives and `Many`/`SepBy` return the error instead of stopping.
+///
+/// Place it after the input that identifies a construct, e.g. after the
+/// keyword `if`, so the error points at the real problem instead of the
+/// start of the construct. User defined errors are returned unchanged
+///
+/// ### Returns:
+/// The result of `parser` unchanged when it succeeds
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::collection_parsers::{choice_parser::Choice, sequence_of_parser::SequenceOf};
+/// use parser_combinator::parsers::{cut_parser::Cut, digits_parser::Digits, str_parser::Str};
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let if_stmt = SequenceOf::new(vec![
+///    Box::new(Str::new("if ".to_owned())),
+///    Box::new(Cut::new(Digits::new()))
+/// ]);
+/// let ident = Str::new("if x".to_owned());
+///
+/// let choice = Choice::new(vec![Box::new(if_stmt), Box::new(ident)]);
+/// let err = choice.run("if x").result.unwrap().unwrap_err();
+///
+/// assert!(err.fatal);
+/// assert_eq!(err.parser, "Digits");
+/// ```
+#[derive(Debug)]
+pub struct Cut<I, R1, R2, T, E = ParseError>
+   where I: Parse<R1, R2, T, E> {
+   parser: I,
+   _p1: PhantomData<R1>,
+   _p2: PhantomData<R2>,
+   _p3: PhantomData<T>,
+   _p4: PhantomData<E>,
+}
+
+impl<I, R1, R2, T, E> Cut<I, R1, R2, T, E>
+   where I: Parse<R1, R2, T, E> {
+   pub fn new(parser: I) -> Self {
+      Self { parser, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData, _p4: PhantomData }
+   }
+}
+
+impl<I, R1, R2, T, E> Parse<R1, R2, T, E> for Cut<I, R1, R2, T, E>
+   where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug, E: CombinatorError,
+      I: Parse<R1, R2, T, E> {
+
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
+      if state.is_error() {
+         return State::from_err_state(state);
+      }
+
+      let mut next = self.parser.transform(state);
+
+      let err = next.result
+         .as_mut()
+         .and_then(|res| res.as_mut().err())
+         .and_then(|err| err.as_parse_error_mut());
+
+      if let Some(err) = err {
+         err.fatal = true;
+      }
+
+      next
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::{
+      many_parser::Many,
+      sep_by_parser::SepBy,
+      sequence_of_parser::SequenceOf
+   };
+   use crate::models::parser_traits::Parse;
+   use crate::parsers::{digits_parser::Digits, str_parser::Str};
+   use super::Cut;
+
+   fn assignment() -> SequenceOf<String, String, &'static str> {
+      SequenceOf::new(vec![
+         Box::new(Str::new("x=".to_owned())),
+         Box::new(Cut::new(Digits::new()))
+      ])
+   }
+
+   #[test]
+   fn many_stops_on_fatal() {
+      let result = Many::new(assignment()).run("x=1x=y");
+      let err = result.result.unwrap().unwrap_err();
+
+      assert!(err.fatal);
+      assert_eq!(err.index, 5);
+   }
+
+   #[test]
+   fn many_backtracks_before_cut() {
+      let result = Many::new(assignment()).run("x=1y=2");
+
+      assert_eq!(result.result.unwrap().unwrap().unwrap_many().len(), 2);
+      assert_eq!(result.index, 3);
+   }
+
+   #[test]
+   fn sep_by_stops_on_fatal() {
+      let parser = SepBy::new(Str::new(",".to_owned()), assignment());
+      let err = parser.run("x=1,x=").result.unwrap().unwrap_err();
+
+      assert!(err.fatal);
+      assert!(err.is_end_of_input());
+   }
+}
@@ -1,21 +1,33 @@
 use std::fmt;
+use std::marker::PhantomData;
 
-use regex::Regex;
-
-use crate::models::{parser_traits::Parse, state::State};
+use crate::models::{parser_traits::Parse, state::State, parse_error::ParseError, input::{Input, FromSlice}};
 use crate::models::cardinality::Cardinality::One;
 
-use super::str_parser::StringState;
+use super::{inside_char, next_unit, out_of_input, take_chars_while};
 
 /// # Digits:
+/// Parse at least one ASCII digit
+///
+/// ### Returns:
+/// The digits as a `String`, or as a `&str` borrowed from a `&str` target
+/// when built with [`Digits::borrowed`]
 #[derive(Clone)]
-pub struct Digits {
-   regex_matcher: Regex
+pub struct Digits<O = String> {
+   _o: PhantomData<O>
 }
 
 impl Digits {
    pub fn new() -> Self {
-      Digits { regex_matcher: Regex::new(r"^[0-9]+").unwrap() }
+      Digits { _o: PhantomData }
+   }
+}
+
+impl Digits<&str> {
+   /// Instantiate a [`Digits`] parser returning a slice of the target instead
+   /// of allocating a `String` for every match
+   pub fn borrowed() -> Self {
+      Digits { _o: PhantomData }
    }
 }
 
@@ -23,50 +35,52 @@ impl Default for Digits {
    fn default() -> Self { Self::new() }
 }
 
-impl fmt::Debug for Digits {
+impl<O> fmt::Debug for Digits<O> {
    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
       f.debug_struct("Digits").finish()
    }
 }
 
-impl Parse<String,String,&str> for Digits {
-   fn transform<'s>(&self, state: StringState<'s>) -> StringState<'s> {      
+impl<T: Input, O: FromSlice<T> + fmt::Debug> Parse<O,O,T> for Digits<O> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {      
       let contains_error = state.is_error();
 
       if contains_error {
          return state;
       }
 
-      if state.index >= state.target.len() {
-         let state = State {
-            index: state.index,
-            target: state.target,
-            result: Some(Err("Digits: Unexpected end of input".to_owned()))
-         };
+      if let Some(err) = inside_char(&*state.target, "Digits", state.index, "digits".to_owned()) {
+         return state.new_err(err);
+      }
 
-         return state;
+      if state.index >= state.target.len() {
+         let err = out_of_input(&*state.target, "Digits", state.index, "digits".to_owned(), 1);
+         return state.new_err(err);
       }
 
-      let match_result = self.regex_matcher.find(&state.target[state.index..]);
+      let match_end = take_chars_while(&*state.target, state.index, |c| c.is_ascii_digit());
 
-      if match_result.is_none() {
-         let state = State {
-            index: state.index,
-            target: state.target,
-            result: Some(Err(format!("Digits: No digits were matched at index: {}", state.index)))
-         };
+      if match_end == state.index {
+         let found = next_unit(&*state.target, state.index);
+         let err = ParseError::mismatch("Digits", state.index, "digits".to_owned(), found);
+         return state.new_err(err);
+      }
 
-         return state;
+      // the match could continue in the data that has not arrived yet
+      if match_end == state.target.len() && state.target.is_partial() {
+         let err = ParseError::incomplete("Digits", state.index, "digits".to_owned(), 1);
+         return state.new_err(err);
       }
 
-      let match_val = match_result.unwrap();
-      let state = State {
-         index: state.index + match_val.end(), 
-         target: state.target.clone(),
-         result: Some(Ok(One(match_val.as_str().to_owned()))) 
-      };
+      let matched = state.target.text(state.index, match_end);
 
-      return state;
+      State {
+         index: match_end,
+         position: state.position.advance(&matched),
+         target: state.target.clone(),
+         result: Some(Ok(One(O::from_slice(&*state.target, state.index, match_end)))),
+         tracker: state.tracker
+      }
    }
 }
 
@@ -87,10 +101,19 @@ mod tests {
    fn digit_fail_run() {
       let p = Digits::new();
       let res = p.run("s123s");
-      assert!(res.result.unwrap().is_err());
+      let err = res.result.unwrap().unwrap_err();
+      assert!(err.is_mismatch());
+      assert_eq!(err.found.as_deref(), Some("s"));
       assert!(res.index == 0);
    }
 
+   #[test]
+   fn digit_end_of_input_run() {
+      let p = Digits::new();
+      let res = p.run("");
+      assert!(res.result.unwrap().unwrap_err().is_end_of_input());
+   }
+
    #[test]
    fn feature() {
       // let regex_matcher = Regex::new(r"^[0-9]+").unwrap();
@@ -0,0 +1,198 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::models::parser_traits::{Parse, CombinatorError};
+use crate::models::state::State;
+use crate::models::tracker::Tracker;
+
+/// # IntoErr:
+/// Adapts a parser raising the built in
+/// [`crate::models::parse_error::ParseError`], such as `Str` or
+/// `Digits`, to a grammar using a user defined error type. The errors of
+/// `parser` are converted with `From<ParseError>`, as are the failures it
+/// recorded for the run
+///
+/// ### Returns:
+/// The result of `parser` unchanged when it succeeds
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::models::parse_error::ParseError;
+/// use parser_combinator::models::parser_traits::{Parse, CombinatorError};
+/// use parser_combinator::models::cardinality::Cardinality;
+/// use parser_combinator::models::state::ParserResult;
+/// use parser_combinator::parser_helpers::map_result;
+/// use parser_combinator::parsers::{digits_parser::Digits, into_err_parser::IntoErr};
+///
+/// #[derive(Debug, Clone)]
+/// enum PortError {
+///    Syntax(ParseError),
+///    OutOfRange(String)
+/// }
+///
+/// impl From<ParseError> for PortError {
+///    fn from(err: ParseError) -> Self { PortError::Syntax(err) }
+/// }
+///
+/// impl CombinatorError for PortError {
+///    fn as_parse_error(&self) -> Option<&ParseError> {
+///       match self { PortError::Syntax(err) => Some(err), _ => None }
+///    }
+///
+///    fn as_parse_error_mut(&mut self) -> Option<&mut ParseError> {
+///       match self { PortError::Syntax(err) => Some(err), _ => None }
+///    }
+/// }
+///
+/// let port = map_result(IntoErr::new(Digits::new()), |res: ParserResult<String, PortError>| {
+///    res.map(|res| res.and_then(|digits| {
+///       let digits = digits.unwrap_one();
+///       match digits.parse::<u16>() {
+///          Ok(port) => Ok(Cardinality::One(port)),
+///          Err(_) => Err(PortError::OutOfRange(digits))
+///       }
+///    }))
+/// });
+///
+/// assert_eq!(port.run("8080").result.unwrap().unwrap().unwrap_one(), 8080);
+/// assert!(matches!(port.run("99999").result.unwrap(), Err(PortError::OutOfRange(_))));
+/// assert!(matches!(port.run("http").result.unwrap(), Err(PortError::Syntax(_))));
+/// ```
+#[derive(Debug)]
+pub struct IntoErr<I, R1, R2, T, E>
+   where I: Parse<R1, R2, T> {
+   parser: I,
+   _p1: PhantomData<R1>,
+   _p2: PhantomData<R2>,
+   _p3: PhantomData<T>,
+   _p4: PhantomData<E>,
+}
+
+impl<I, R1, R2, T, E> IntoErr<I, R1, R2, T, E>
+   where I: Parse<R1, R2, T> {
+   pub fn new(parser: I) -> Self {
+      Self { parser, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData, _p4: PhantomData }
+   }
+}
+
+impl<I, R1, R2, T, E> Parse<R1, R2, T, E> for IntoErr<I, R1, R2, T, E>
+   where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug, E: CombinatorError,
+      I: Parse<R1, R2, T> {
+
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
+      if state.is_error() {
+         return State::from_err_state(state);
+      }
+
+      // the inner parser records its failures in a tracker of its own error
+      // type, they are moved to the run's tracker once it is done
+      let inner_tracker = Rc::new(Tracker::new());
+      let inner = State {
+         index: state.index,
+         target: Rc::clone(&state.target),
+         position: state.position,
+         result: None,
+         tracker: Rc::clone(&inner_tracker)
+      };
+      let next = self.parser.transform(inner);
+      state.tracker.absorb(&inner_tracker);
+
+      State {
+         index: next.index,
+         target: next.target,
+         position: next.position,
+         result: next.result.map(|res| res.map_err(E::from)),
+         tracker: state.tracker
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::{choice_parser::Choice, sequence_of_parser::SequenceOf};
+   use crate::models::cardinality::Cardinality;
+   use crate::models::parse_error::ParseError;
+   use crate::models::parser_traits::{Parse, CombinatorError};
+   use crate::models::state::ParserResult;
+   use crate::parser_helpers::map_result;
+   use crate::parsers::{digits_parser::Digits, str_parser::Str};
+   use super::IntoErr;
+
+   #[derive(Debug, Clone, PartialEq)]
+   enum ByteError {
+      Syntax(ParseError),
+      OutOfRange(String)
+   }
+
+   impl From<ParseError> for ByteError {
+      fn from(err: ParseError) -> Self { ByteError::Syntax(err) }
+   }
+
+   impl CombinatorError for ByteError {
+      fn as_parse_error(&self) -> Option<&ParseError> {
+         match self { ByteError::Syntax(err) => Some(err), _ => None }
+      }
+
+      fn as_parse_error_mut(&mut self) -> Option<&mut ParseError> {
+         match self { ByteError::Syntax(err) => Some(err), _ => None }
+      }
+   }
+
+   // the closure returns the user error by value like the results of the combinators
+   #[allow(clippy::result_large_err)]
+   fn byte() -> impl Parse<String, String, &'static str, ByteError> {
+      map_result(IntoErr::new(Digits::new()), |res: ParserResult<String, ByteError>| {
+         res.map(|res| res.and_then(|digits| {
+            let digits = digits.unwrap_one();
+            match digits.parse::<u8>() {
+               Ok(_) => Ok(Cardinality::One(digits)),
+               Err(_) => Err(ByteError::OutOfRange(digits))
+            }
+         }))
+      })
+   }
+
+   #[test]
+   fn user_error_passes_through() {
+      let parser = SequenceOf::new(vec![
+         Box::new(IntoErr::new(Str::new("0x".to_owned()))),
+         Box::new(byte())
+      ]);
+
+      let err = parser.run("0x300").result.unwrap().unwrap_err();
+      assert_eq!(err, ByteError::OutOfRange("300".to_owned()));
+   }
+
+   #[test]
+   fn choice_reports_user_error() {
+      let parser: Choice<String, String, &str, ByteError> = Choice::new(vec![
+         Box::new(byte()),
+         Box::new(IntoErr::new(Str::new("x".to_owned())))
+      ]);
+
+      let err = parser.run("256").result.unwrap().unwrap_err();
+      assert_eq!(err, ByteError::OutOfRange("256".to_owned()));
+   }
+
+   #[test]
+   fn converts_farthest_failure() {
+      let parser = SequenceOf::new(vec![
+         Box::new(IntoErr::new(Str::new("0x".to_owned()))),
+         Box::new(byte())
+      ]);
+
+      let result = parser.run("0xff");
+      match result.result.unwrap().unwrap_err() {
+         ByteError::Syntax(err) => {
+            assert_eq!(err.parser, "Digits");
+            assert_eq!(err.index, 2);
+         },
+         err => panic!("unexpected error {:?}", err)
+      }
+      assert!(result.tracker.farthest().is_some());
+   }
+}
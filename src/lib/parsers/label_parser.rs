@@ -0,0 +1,151 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::models::parse_error::ParseError;
+use crate::models::parser_traits::{Parse, CombinatorError};
+use crate::models::state::State;
+
+/// # Label:
+/// Gives a human readable name to the construct parsed by `parser`.
+/// If the parser fails without consuming any input its expected values are
+/// replaced by the label, either way the label is pushed onto the context
+/// of the error, producing messages such as
+/// "while parsing function call > argument list: ... expected ')'"
+///
+/// ### Returns:
+/// The result of `parser` unchanged when it succeeds
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::parsers::label_parser::Label;
+/// use parser_combinator::parsers::str_parser::Str;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let keyword = Label::new("keyword", Str::new("lambda".to_owned()));
+/// let err = keyword.run("lamb").result.unwrap().unwrap_err();
+///
+/// assert_eq!(err.expected, vec!["keyword"]);
+/// assert_eq!(err.context, vec!["keyword"]);
+/// ```
+#[derive(Debug)]
+pub struct Label<I, R1, R2, T, E = ParseError>
+   where I: Parse<R1, R2, T, E> {
+   label: String,
+   parser: I,
+   _p1: PhantomData<R1>,
+   _p2: PhantomData<R2>,
+   _p3: PhantomData<T>,
+   _p4: PhantomData<E>,
+}
+
+impl<I, R1, R2, T, E> Label<I, R1, R2, T, E>
+   where I: Parse<R1, R2, T, E> {
+   /// Instantiate a [`Label`] parser
+   ///
+   /// ## Args:
+   /// * `label` - The name of the construct displayed in errors
+   /// * `parser` - The parser for the construct
+   pub fn new(label: &str, parser: I) -> Self {
+      Self { label: label.to_owned(), parser, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData, _p4: PhantomData }
+   }
+}
+
+impl<I, R1, R2, T, E> Parse<R1, R2, T, E> for Label<I, R1, R2, T, E>
+   where R1: fmt::Debug, R2: fmt::Debug, T: fmt::Debug, E: CombinatorError,
+      I: Parse<R1, R2, T, E> {
+
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
+      if state.is_error() {
+         return State::from_err_state(state);
+      }
+
+      let start_index = state.index;
+      let mut next = self.parser.transform(state);
+
+      let err = next.result
+         .as_mut()
+         .and_then(|res| res.as_mut().err())
+         .and_then(|err| err.as_parse_error_mut());
+
+      if let Some(err) = err {
+         if err.index == start_index {
+            err.expected = vec![self.label.clone()];
+         }
+
+         err.context.insert(0, self.label.clone());
+      }
+
+      next
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::{many_parser::Many, sequence_of_parser::SequenceOf};
+   use crate::models::parser_traits::Parse;
+   use crate::parsers::{digits_parser::Digits, str_parser::Str};
+   use super::Label;
+
+   #[test]
+   fn success_unchanged() {
+      let parser = Label::new("keyword", Str::new("let".to_owned()));
+      let res = parser.run("let");
+
+      assert_eq!(res.result.unwrap().unwrap().unwrap_one(), "let");
+      assert_eq!(res.index, 3);
+   }
+
+   #[test]
+   fn nested_context() {
+      let args = Label::new("argument list", SequenceOf::new(vec![
+         Box::new(Str::new("(".to_owned())),
+         Box::new(Str::new(")".to_owned()))
+      ]));
+      let call = Label::new("function call", SequenceOf::new(vec![
+         Box::new(Str::new("f".to_owned())),
+         Box::new(args)
+      ]));
+
+      let err = call.run("f(x").result.unwrap().unwrap_err();
+
+      assert_eq!(err.context, vec!["function call", "argument list"]);
+      assert_eq!(err.expected, vec!["')'"]);
+      assert_eq!(
+         err.to_string(),
+         "while parsing function call > argument list: Str: unexpected input, expected ')' but found 'x' at index 2");
+   }
+
+   #[test]
+   fn replaces_expected_without_progress() {
+      let args = Label::new("argument list", SequenceOf::new(vec![
+         Box::new(Str::new("(".to_owned())),
+         Box::new(Str::new(")".to_owned()))
+      ]));
+
+      let err = args.run("x").result.unwrap().unwrap_err();
+      assert_eq!(err.expected, vec!["argument list"]);
+   }
+
+   #[test]
+   fn keeps_context_of_farthest_failure() {
+      let item = SequenceOf::new(vec![
+         Box::new(Str::new(" ".to_owned())),
+         Box::new(Digits::new())
+      ]);
+      let list = Label::new("list", SequenceOf::new(vec![
+         Box::new(Str::new("(".to_owned())),
+         Box::new(Many::new(item)),
+         Box::new(Str::new(")".to_owned()))
+      ]));
+
+      // Many backtracks on " x", the farthest failure is the Digits at index 6
+      let err = list.run("( 1 2 x)").result.unwrap().unwrap_err();
+
+      assert_eq!(err.parser, "Digits");
+      assert_eq!(err.index, 6);
+      assert_eq!(err.context, vec!["list"]);
+   }
+}
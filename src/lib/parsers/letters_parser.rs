@@ -1,66 +1,107 @@
 use std::fmt;
+use std::marker::PhantomData;
 
-use regex::Regex;
-
-use crate::models::{parser_traits::Parse, state::State};
+use crate::models::{parser_traits::Parse, state::State, parse_error::ParseError, input::{Input, FromSlice}};
 use crate::models::cardinality::Cardinality::One;
 
+use super::{inside_char, is_cut_char, next_unit, out_of_input, take_chars_while, window};
+
 /// # Letters
+/// Parse at least one ASCII letter, or at least one Unicode letter (e.g.
+/// `é`, `λ` or `字`) once built with [`Letters::unicode`]
+///
+/// ### Returns:
+/// The letters as a `String`, or as a `&str` borrowed from a `&str` target
+/// when built with [`Letters::borrowed`]
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::parsers::letters_parser::Letters;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let result = Letters::new().unicode().run("größe = 2");
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), "größe");
+/// assert_eq!(result.index, 7);
+/// ```
 #[derive(Clone)]
-pub struct Letters {
-   regex_matcher: Regex
+pub struct Letters<O = String> {
+   is_match: fn(char) -> bool,
+   _o: PhantomData<O>
 }
 
 impl Letters {
    pub fn new() -> Self {
-      Letters { regex_matcher: Regex::new(r"^[A-Za-z]+").unwrap() }
-   } 
+      Letters { is_match: |c| c.is_ascii_alphabetic(), _o: PhantomData }
+   }
+}
+
+impl Letters<&str> {
+   /// Instantiate a [`Letters`] parser returning a slice of the target instead
+   /// of allocating a `String` for every match
+   pub fn borrowed() -> Self {
+      Letters { is_match: |c| c.is_ascii_alphabetic(), _o: PhantomData }
+   }
+}
+
+impl<O> Letters<O> {
+   /// Match the letters of every script instead of ASCII letters only
+   pub fn unicode(self) -> Self {
+      Letters { is_match: char::is_alphabetic, _o: PhantomData }
+   }
 }
 
 impl Default for Letters {
    fn default() -> Self { Self::new() }
 }
 
-impl fmt::Debug for Letters {
+impl<O> fmt::Debug for Letters<O> {
    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
       f.debug_struct("Letters").finish()
    }
 }
 
-impl Parse<String,String,String> for Letters {
-   fn transform(&self, state: State<String, String>) -> State<String, String> {
-      println!("{:?}", self);
-      println!("\t{:?}", state);
-      println!("");
+impl<T: Input, O: FromSlice<T> + fmt::Debug> Parse<O,O,T> for Letters<O> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {
       let contains_error = state.is_error();
 
       if contains_error {
          return state;
       }
 
+      if let Some(err) = inside_char(&*state.target, "Letters", state.index, "letters".to_owned()) {
+         return state.new_err(err);
+      }
+
       if state.index >= state.target.len() {
-         return State {
-            index: state.index,
-            target: state.target,
-            result: Some(Err("Letters: Unexpected end of input".to_owned()))
-         }
+         let err = out_of_input(&*state.target, "Letters", state.index, "letters".to_owned(), 1);
+         return state.new_err(err);
       }
 
-      let match_result = self.regex_matcher.find_at(&state.target, state.index);
+      let match_end = take_chars_while(&*state.target, state.index, self.is_match);
+
+      // the match could continue in the data that has not arrived yet,
+      // possibly with the rest of a char cut by the end of the target
+      let rest = window(&*state.target, match_end, 4);
+      if state.target.is_partial() && (rest.is_empty() || is_cut_char(&rest)) {
+         let err = ParseError::incomplete("Letters", state.index, "letters".to_owned(), 1);
+         return state.new_err(err);
+      }
 
-      if match_result.is_none() {
-         return State {
-            index: state.index,
-            target: state.target,
-            result: Some(Err(format!("Letters: No letters were matched at index: {}", state.index)))
-         }
+      if match_end == state.index {
+         let found = next_unit(&*state.target, state.index);
+         let err = ParseError::mismatch("Letters", state.index, "letters".to_owned(), found);
+         return state.new_err(err);
       }
 
-      let match_val = match_result.unwrap();
-      return State { 
-         index: state.index + match_val.end(), 
+      let matched = state.target.text(state.index, match_end);
+      State { 
+         index: match_end,
+         position: state.position.advance(&matched),
          target: state.target.clone(), // TODO: Work on clone 
-         result: Some(Ok(One(match_val.as_str().to_owned()))) 
+         result: Some(Ok(One(O::from_slice(&*state.target, state.index, match_end)))),
+         tracker: state.tracker
       }
    }
 }
@@ -68,6 +109,7 @@ impl Parse<String,String,String> for Letters {
 #[cfg(test)]
 mod tests {
     use crate::models::parser_traits::Parse;
+    use crate::streaming::Partial;
     use super::Letters;
 
    #[test]
@@ -85,4 +127,31 @@ mod tests {
       assert!(res.result.unwrap().is_err());
       assert!(res.index == 0);
    }
+
+   #[test]
+   fn letters_ascii_stops_at_accent() {
+      let res = Letters::new().run("café");
+      assert_eq!(res.result.unwrap().unwrap().unwrap_one(), "caf");
+      assert_eq!(res.index, 3);
+
+      let err = Letters::new().run("élan").result.unwrap().unwrap_err();
+      assert_eq!(err.found.as_deref(), Some("é"));
+   }
+
+   #[test]
+   fn letters_unicode_run() {
+      let res = Letters::borrowed().unicode().run("λόγος字1");
+      assert_eq!(res.result.unwrap().unwrap().unwrap_one(), "λόγος字");
+      assert_eq!(res.index, 13);
+   }
+
+   #[test]
+   fn letters_waits_for_cut_char() {
+      let bytes = "añ".as_bytes();
+      let res = Letters::new().unicode().run(Partial::new(&bytes[..2], false));
+      assert!(res.result.unwrap().unwrap_err().is_incomplete());
+
+      let res = Letters::new().unicode().run(Partial::new(&bytes[..2], true));
+      assert_eq!(res.result.unwrap().unwrap().unwrap_one(), "a");
+   }
 }
\ No newline at end of file
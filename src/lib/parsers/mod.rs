@@ -2,4 +2,200 @@
 pub mod str_parser;
 pub mod letters_parser;
 pub mod digits_parser;
-pub mod between_parser;
\ No newline at end of file
+pub mod between_parser;
+pub mod label_parser;
+pub mod recover_parser;
+pub mod cut_parser;
+pub mod into_err_parser;
+pub mod char_parser;
+pub mod any_char_parser;
+pub mod alphanumeric_parser;
+
+use std::borrow::Cow;
+
+use crate::models::{input::Input, parse_error::ParseError};
+
+/// The error raised when `parser` runs out of input at `index`, an
+/// incomplete error needing `needed` more bytes if the target is partial
+pub(crate) fn out_of_input<T: Input>(target: &T, parser: &str, index: usize, expected: String, needed: usize) -> ParseError {
+   if target.is_partial() {
+      ParseError::incomplete(parser, index, expected, needed)
+   } else {
+      ParseError::end_of_input(parser, index, expected)
+   }
+}
+
+/// The error raised when a text parser starts inside a char of `target`,
+/// e.g. after a byte parser consumed part of it. Returns `None` on a char
+/// boundary, the only indexes a text target can be sliced at
+pub(crate) fn inside_char<T: Input>(target: &T, parser: &str, index: usize, expected: String) -> Option<ParseError> {
+   if target.is_boundary(index) {
+      return None;
+   }
+
+   let mut err = ParseError::custom(parser, index, "cannot start inside a char".to_owned());
+   err.expected.push(expected);
+   Some(err)
+}
+
+/// The bytes of `target` from `index`, at most `len` of them. They are
+/// only copied when they span several chunks of the target
+pub(crate) fn window<T: Input>(target: &T, index: usize, len: usize) -> Cow<'_, [u8]> {
+   target.slice(index, (index + len).min(target.len()))
+}
+
+/// The char encoded in UTF-8 at `index` of `target`, `None` if the bytes
+/// there are not a valid char
+pub(crate) fn char_at<T: Input>(target: &T, index: usize) -> Option<char> {
+   let rest = window(target, index, 4);
+   let valid = match std::str::from_utf8(&rest) {
+      Ok(valid) => valid,
+      Err(err) => std::str::from_utf8(&rest[..err.valid_up_to()]).unwrap_or_default()
+   };
+
+   valid.chars().next()
+}
+
+/// The index reached after consuming the chars of `target` from `index`
+/// as long as `predicate` holds for them
+pub(crate) fn take_chars_while<T: Input, F: Fn(char) -> bool>(target: &T, index: usize, predicate: F) -> usize {
+   let mut end = index;
+   while let Some(c) = char_at(target, end).filter(|c| predicate(*c)) {
+      end += c.len_utf8();
+   }
+
+   end
+}
+
+/// Returns `true` if `bytes` starts with a char cut short by their end,
+/// e.g. the part of a char read so far from a stream
+pub(crate) fn is_cut_char(bytes: &[u8]) -> bool {
+   match std::str::from_utf8(&bytes[..bytes.len().min(4)]) {
+      Ok(_) => false,
+      Err(err) => err.valid_up_to() == 0 && err.error_len().is_none()
+   }
+}
+
+/// The unit (char or byte) of `target` at `index` as the `found` value of a
+/// [`crate::models::parse_error::ParseError`], a whole char if one starts
+/// there even in a binary target
+pub(crate) fn next_unit<T: Input>(target: &T, index: usize) -> String {
+   match char_at(target, index) {
+      Some(c) => c.to_string(),
+      None => target.text(index, index + target.unit_len(index)).into_owned()
+   }
+}
+
+/// The index reached after skipping up to `count` units (chars or bytes)
+/// of `target` from `index`
+pub(crate) fn skip_units<T: Input>(target: &T, index: usize, count: usize) -> usize {
+   let mut end = index;
+   for _ in 0..count {
+      match target.unit_len(end) {
+         0 => break,
+         len => end += len
+      }
+   }
+
+   end
+}
+
+/// The number of edits turning `a` into `b`, an edit being the insertion,
+/// deletion or substitution of a char or the swap of two adjacent chars
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+   let a: Vec<char> = a.chars().collect();
+   let b: Vec<char> = b.chars().collect();
+
+   // distances[i][j] is the distance between the first i chars of `a`
+   // and the first j chars of `b`
+   let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+   for (i, row) in distances.iter_mut().enumerate() {
+      row[0] = i;
+   }
+   for (j, distance) in distances[0].iter_mut().enumerate() {
+      *distance = j;
+   }
+
+   for i in 1..=a.len() {
+      for j in 1..=b.len() {
+         let substitution = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+         let mut distance = (distances[i - 1][j] + 1)
+            .min(distances[i][j - 1] + 1)
+            .min(distances[i - 1][j - 1] + substitution);
+
+         if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+            distance = distance.min(distances[i - 2][j - 2] + 1);
+         }
+
+         distances[i][j] = distance;
+      }
+   }
+
+   distances[a.len()][b.len()]
+}
+
+/// Returns `literal` if the start of `target` looks like a typo of it.
+/// A literal made of word chars is compared to the whole word at the start
+/// of `target`, so insertions and deletions are caught as well
+pub(crate) fn suggest(target: &str, literal: &str) -> Option<String> {
+   let is_word = |c: char| c.is_alphanumeric() || c == '_';
+   let length = literal.chars().count();
+
+   let found: String = if literal.chars().all(is_word) {
+      target.chars().take_while(|c| is_word(*c)).collect()
+   } else {
+      target.chars().take(length).collect()
+   };
+
+   if found.is_empty() {
+      return None;
+   }
+
+   // allow one edit for every three chars, a typo must still leave most of
+   // the literal in place
+   let distance = edit_distance(&found, literal);
+   let max_distance = (length / 3).max(1);
+
+   if distance > 0 && distance <= max_distance && distance < length {
+      Some(literal.to_owned())
+   } else {
+      None
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::{char_at, edit_distance, is_cut_char, suggest};
+
+   #[test]
+   fn edit_distance_counts_swaps() {
+      assert_eq!(edit_distance("lambda", "lambda"), 0);
+      assert_eq!(edit_distance("lamda", "lambda"), 1);
+      assert_eq!(edit_distance("nmae", "name"), 1);
+      assert_eq!(edit_distance("", "let"), 3);
+      assert_eq!(edit_distance("kitten", "sitting"), 3);
+   }
+
+   #[test]
+   fn suggests_near_misses() {
+      assert_eq!(suggest("lamda x", "lambda").as_deref(), Some("lambda"));
+      assert_eq!(suggest("lambdaa", "lambda").as_deref(), Some("lambda"));
+      assert_eq!(suggest("define", "lambda"), None);
+      assert_eq!(suggest("x", "("), None);
+      assert_eq!(suggest("", "let"), None);
+   }
+
+   #[test]
+   fn decodes_chars() {
+      let bytes = "añ字".as_bytes();
+      assert_eq!(char_at(&bytes, 1), Some('ñ'));
+      assert_eq!(char_at(&bytes, 3), Some('字'));
+      assert_eq!(char_at(&bytes, 2), None);
+      assert_eq!(char_at(&&bytes[..5], 3), None);
+
+      assert!(is_cut_char(&bytes[3..5]));
+      assert!(!is_cut_char(&bytes[2..]));
+      assert!(!is_cut_char(&bytes[3..]));
+      assert!(!is_cut_char(b""));
+   }
+}
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::models::cardinality::Cardinality::One;
+use crate::models::input::Input;
+use crate::models::parse_error::ParseError;
+use crate::models::parser_traits::{Parse, CombinatorError};
+use crate::models::state::State;
+use crate::models::tracker::Tracker;
+
+/// # Recover:
+/// Runs `parser` and, when it fails, recovers instead of stopping the parse:
+/// the error is collected in the run's [`Tracker`], the input is skipped
+/// one char (one byte for binary targets) at a time up to and including the
+/// next match of the `sync` parser (or the end of the target) and the
+/// `placeholder` value is returned in place of the result. A match of
+/// `sync` consuming no input is skipped, so a recovery always moves forward.
+///
+/// A failure at the end of the target or on incomplete input is not
+/// recovered from, so wrapping parsers such as
+/// [`crate::collection_parsers::many_parser::Many`] still stop
+///
+/// ### Returns:
+/// The result of `parser` or `placeholder` if it failed
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::collection_parsers::{many_parser::Many, sequence_of_parser::SequenceOf};
+/// use parser_combinator::parsers::{digits_parser::Digits, recover_parser::Recover, str_parser::Str};
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let statement = SequenceOf::new(vec![Box::new(Digits::new()), Box::new(Str::new(";".to_owned()))]);
+/// let recover = Recover::new(statement, Str::new(";".to_owned()), "<error>".to_owned());
+/// let result = Many::new(recover).run("1;x;2;");
+///
+/// let values = result.result.unwrap().unwrap().unwrap_many();
+/// assert_eq!(values, vec!["1", ";", "<error>", "2", ";"]);
+/// assert_eq!(result.tracker.errors().len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct Recover<I, S, R1, R2, T, E = ParseError>
+   where I: Parse<R1, R2, T, E>,
+      S: Parse<R1, R2, T, E> {
+   parser: I,
+   sync: S,
+   placeholder: R2,
+   _p1: PhantomData<R1>,
+   _p2: PhantomData<T>,
+   _p3: PhantomData<E>,
+}
+
+impl<I, S, R1, R2, T, E> Recover<I, S, R1, R2, T, E>
+   where I: Parse<R1, R2, T, E>,
+      S: Parse<R1, R2, T, E> {
+   /// Instantiate a [`Recover`] parser
+   ///
+   /// ## Args:
+   /// * `parser` - The parser to recover from
+   /// * `sync` - The parser marking where parsing can resume (e.g. `;`)
+   /// * `placeholder` - The value returned in place of a failed result
+   pub fn new(parser: I, sync: S, placeholder: R2) -> Self {
+      Self { parser, sync, placeholder, _p1: PhantomData, _p2: PhantomData, _p3: PhantomData }
+   }
+}
+
+impl<I, S, R1, R2, T, E> Parse<R1, R2, T, E> for Recover<I, S, R1, R2, T, E>
+   where R1: fmt::Debug, R2: fmt::Debug + Clone, T: Input, E: CombinatorError,
+      I: Parse<R1, R2, T, E>,
+      S: Parse<R1, R2, T, E> {
+
+   fn transform(&self, state: State<R1, T, E>) -> State<R2, T, E> {
+      if state.is_error() {
+         return State::from_err_state(state);
+      }
+
+      let start: State<R1, T, E> = state.reset();
+      let next = self.parser.transform(state);
+
+      let err = match next.result {
+         Some(Err(err)) if start.index < start.target.len() && !err.is_incomplete() => err,
+         _ => return next
+      };
+
+      // skip from the furthest of the start and the error, the index must
+      // stay on a unit boundary for the target to be sliced. User defined
+      // errors have no index, skip from the start
+      let target = Rc::clone(&start.target);
+      let err_index = err.as_parse_error().map(|err| err.index).unwrap_or(start.index);
+      let mut index = err_index.clamp(start.index, target.len());
+      while !target.is_boundary(index) {
+         index -= 1;
+      }
+
+      let mut position = start.position.advance(&target.text(start.index, index));
+      start.tracker.push_error(err);
+
+      // probe the sync parser with its own tracker so the failed attempts
+      // are not reported as the farthest failure of the run
+      let probe_tracker = Rc::new(Tracker::new());
+      loop {
+         let probe = State {
+            index,
+            target: Rc::clone(&target),
+            position,
+            result: None,
+            tracker: Rc::clone(&probe_tracker)
+         };
+         let synced: State<R2, T, E> = self.sync.transform(probe);
+
+         // a zero width match (e.g. an optional newline) would resume at the
+         // same index and fail again forever inside `Many`
+         if !synced.is_error() && synced.index > index {
+            index = synced.index;
+            position = synced.position;
+            break;
+         }
+
+         match target.unit_len(index) {
+            0 => break,
+            len => {
+               position = position.advance(&target.text(index, index + len));
+               index += len;
+            }
+         }
+      }
+
+      State {
+         index,
+         target,
+         position,
+         result: Some(Ok(One(self.placeholder.clone()))),
+         tracker: start.tracker
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::{many_parser::Many, sequence_of_parser::SequenceOf};
+   use crate::models::parser_traits::Parse;
+   use crate::parsers::{digits_parser::Digits, str_parser::Str};
+   use super::Recover;
+
+   fn statement() -> SequenceOf<String, String, &'static str> {
+      SequenceOf::new(vec![
+         Box::new(Str::new("let ".to_owned())),
+         Box::new(Digits::new()),
+         Box::new(Str::new(";".to_owned()))
+      ])
+   }
+
+   #[test]
+   fn collects_every_error() {
+      let recover = Recover::new(statement(), Str::new(";".to_owned()), "<error>".to_owned());
+      let result = Many::new(recover).run("let 1;let x;let 2;lt 3;let 4;");
+
+      let values = result.result.unwrap().unwrap().unwrap_many();
+      assert_eq!(values, vec!["let ", "1", ";", "<error>", "let ", "2", ";", "<error>", "let ", "4", ";"]);
+      assert_eq!(result.index, 29);
+
+      let errors = result.tracker.errors();
+      assert_eq!(errors.len(), 2);
+      assert_eq!(errors[0].index, 10);
+      assert_eq!(errors[0].parser, "Digits");
+      assert_eq!(errors[1].index, 18);
+   }
+
+   #[test]
+   fn zero_width_sync_is_skipped() {
+      // the sync also matches, without consuming anything, where there is no newline
+      let recover = Recover::new(statement(), Many::new(Str::new("\n".to_owned())), "<error>".to_owned());
+      let result = Many::new(recover).run("let 1;ab\nlet 2;");
+
+      let values = result.result.unwrap().unwrap().unwrap_many();
+      assert_eq!(values, vec!["let ", "1", ";", "<error>", "let ", "2", ";"]);
+      assert_eq!(result.index, 15);
+      assert_eq!(result.tracker.errors().len(), 1);
+   }
+
+   #[test]
+   fn skips_to_end_without_sync() {
+      let recover = Recover::new(statement(), Str::new(";".to_owned()), "<error>".to_owned());
+      let result = recover.run("let x");
+
+      assert_eq!(result.result.unwrap().unwrap().unwrap_one(), "<error>");
+      assert_eq!(result.index, 5);
+      assert_eq!(result.tracker.errors().len(), 1);
+   }
+
+   #[test]
+   fn end_of_input_not_recovered() {
+      let recover = Recover::new(statement(), Str::new(";".to_owned()), "<error>".to_owned());
+      let result = recover.run("");
+
+      assert!(result.result.unwrap().is_err());
+      assert!(result.tracker.errors().is_empty());
+   }
+}
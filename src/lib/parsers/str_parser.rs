@@ -1,28 +1,58 @@
-use std::rc::Rc;
-
 use crate::models::parser_traits::Parse;
 use crate::models::state::State;
 use crate::models::cardinality::Cardinality;
+use crate::models::parse_error::ParseError;
+use crate::models::input::{Input, FromSlice};
+use super::{skip_units, out_of_input, inside_char, window};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::rc::Rc;
 
 pub type StringState<'state> = State<String, &'state str>;
 
 /// # Str:
-/// Parse a specific string in the target
+/// Parse a specific string in any [`Input`] target. When the input is a near miss
+/// of the string, e.g. a typo of a keyword, the error suggests it
+///
+/// ### Returns:
+/// The matched string as a `String`, or as a `&str` borrowed from a `&str`
+/// target when built with [`Str::borrowed`]
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::parsers::str_parser::Str;
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// let target = String::from("let x");
+/// let result = Str::borrowed("let".to_owned()).run(target.as_str());
+///
+/// let keyword: &str = result.result.unwrap().unwrap().unwrap_one();
+/// assert_eq!(keyword, "let");
+/// ```
 #[derive(Clone, Debug)]
-pub struct  Str {
-   pub to_match: String
+pub struct  Str<O = String> {
+   pub to_match: String,
+   _o: PhantomData<O>
 }
 
 impl Str {
    pub fn new(to_match: String) -> Self {
-      Self { to_match }
+      Self { to_match, _o: PhantomData }
    }
 }
 
+impl Str<&str> {
+   /// Instantiate a [`Str`] returning a slice of the target instead of
+   /// allocating a `String` for every match
+   pub fn borrowed(to_match: String) -> Self {
+      Self { to_match, _o: PhantomData }
+   }
+}
 
-impl Parse<String,String,&str> for Str {
+impl<T: Input, O: FromSlice<T> + Debug> Parse<O,O,T> for Str<O> {
 
-   fn transform<'s>(&self, state: StringState<'s>) -> StringState<'s> {
+   fn transform(&self, state: State<O, T>) -> State<O, T> {
 
       let contains_error = state.is_error();
          
@@ -31,30 +61,46 @@ impl Parse<String,String,&str> for Str {
       }
 
       let start_index = state.index;
-      let sliced_target = &state.target[start_index..];
-      if sliced_target.is_empty() {
-         let err_state = state.new_err(String::from("Str: Unexpected end of input"));
+      let target = Rc::clone(&state.target);
+      let to_match = self.to_match.as_bytes();
+      let sliced_target = window(&*target, start_index, to_match.len());
+      let expected = format!("'{}'", self.to_match);
 
-         return err_state;
+      if let Some(err) = inside_char(&*target, "Str", start_index, expected.clone()) {
+         return state.new_err(err);
       }
 
-      if sliced_target.starts_with(self.to_match.as_str()) {
+      if *sliced_target == *to_match {
+         let end = start_index + to_match.len();
          let res = State {
+            index: end,
+            position: state.position.advance(&self.to_match),
+            result: Some(Ok(Cardinality::One(O::from_slice(&*target, start_index, end)))),
             target: state.target,
-            index: start_index + self.to_match.len(),
-            result: Some(Ok(Cardinality::One(self.to_match.clone())))
+            tracker: state.tracker
          };
 
          return res;
       }
 
-      let res = State { 
-         index: start_index, 
-         target: Rc::clone(&state.target),
-         result: Some(Err(format!("Str: Tried to match {}, but got {}", self.to_match, state.target)))
-      };
+      if to_match.starts_with(&sliced_target) {
+         let needed = to_match.len() - sliced_target.len();
+         let err = out_of_input(&*target, "Str", start_index, expected, needed);
+         return state.new_err(err);
+      }
+
+      let found_end = skip_units(&*target, start_index, self.to_match.chars().count());
+      let found = target.text(start_index, found_end).into_owned();
+
+      // a typo is at most a few units longer than the string, there is no
+      // need to decode the rest of the target to look for one
+      let window_end = (start_index + 2 * to_match.len() + 4).min(target.len());
+      let window = target.text(start_index, window_end).into_owned();
 
-      return res;
+      // whether it is a typo is only checked if the error is reported
+      let mut err = ParseError::mismatch("Str", start_index, expected, found);
+      err.add_near_miss(&self.to_match, window);
+      state.new_err(err)
    }
 }
 
@@ -91,6 +137,61 @@ mod tests {
    fn str_fail_short_target_parse() {
       let parser = Str::new("Test".to_owned());
       let res = parser.run("T");
-      assert!(res.result.unwrap().is_err());
+      assert!(res.result.unwrap().unwrap_err().is_end_of_input());
+   }
+
+   #[test]
+   fn str_fail_reports_found() {
+      let parser = Str::new("Test".to_owned());
+      let err = parser.run("Abcde").result.unwrap().unwrap_err();
+      assert!(err.is_mismatch());
+      assert_eq!(err.expected, vec!["'Test'"]);
+      assert_eq!(err.found.as_deref(), Some("Abcd"));
+      assert!(err.suggestions().is_empty());
+   }
+
+   #[test]
+   fn str_borrowed_outlives_run() {
+      use crate::collection_parsers::sep_by_parser::SepBy;
+      use crate::parsers::letters_parser::Letters;
+
+      // the words borrow from the target, not from the state of the run
+      fn words(target: &str) -> Vec<&str> {
+         let parser = SepBy::new(Str::borrowed(",".to_owned()), Letters::borrowed());
+         parser.run(target).result.unwrap().unwrap().unwrap_many()
+      }
+
+      let target = "alpha,beta,gamma".to_owned();
+      assert_eq!(words(&target), vec!["alpha", "beta", "gamma"]);
+   }
+
+   #[test]
+   fn str_fail_inside_char() {
+      use std::rc::Rc;
+      use crate::models::state::State;
+
+      // e.g. after a byte parser stopping in the middle of the 'é'
+      let mut state: State<String, &str> = State::new(Rc::new("héx"));
+      state.index = 2;
+      let err = Str::new("x".to_owned()).transform(state).result.unwrap().unwrap_err();
+
+      assert_eq!(err.index, 2);
+      assert_eq!(err.to_string(), "Str: cannot start inside a char, expected 'x' at index 2");
+   }
+
+   #[test]
+   fn str_unicode_parse() {
+      let res = Str::new("ñandú".to_owned()).run("ñandú!");
+      assert_eq!(res.result.unwrap().unwrap().unwrap_one(), "ñandú");
+      assert_eq!(res.index, 7);
+      assert_eq!(res.position.column, 6);
+   }
+
+   #[test]
+   fn str_fail_suggests_near_miss() {
+      let parser = Str::new("lambda".to_owned());
+      let err = parser.run("lamda (x) x").result.unwrap().unwrap_err();
+      assert_eq!(err.suggestions(), vec!["lambda"]);
+      assert_eq!(err.did_you_mean().unwrap(), "did you mean 'lambda'?");
    }
 }
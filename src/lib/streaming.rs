@@ -0,0 +1,324 @@
+use std::{borrow::Cow, error::Error, fmt, io::{self, Read}, marker::PhantomData, mem, rc::Rc};
+
+use crate::models::{
+   cardinality::Cardinality,
+   input::{Input, FromSlice},
+   parse_error::{ParseError, ErrorKind},
+   parser_traits::Parse
+};
+
+/// The number of bytes read from the reader at a time by default
+pub const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+/// # Partial
+/// Wraps a target that may be followed by more data, e.g. the part of a
+/// file or socket read so far. Parsers reaching its end fail with
+/// [`ErrorKind::Incomplete`] instead of an end of input error, as do the
+/// parsers whose match could continue past it (e.g. `Digits`), until it is
+/// marked as `complete`
+///
+/// ### Examples
+///
+/// ```
+/// use parser_combinator::parsers::digits_parser::Digits;
+/// use parser_combinator::models::parser_traits::Parse;
+/// use parser_combinator::streaming::Partial;
+///
+/// let result = Digits::new().run(Partial::new("12", false));
+/// assert!(result.result.unwrap().unwrap_err().is_incomplete());
+///
+/// let result = Digits::new().run(Partial::new("12", true));
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one(), "12");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Partial<T> {
+   pub input: T,
+   /// Set once no more data can follow the input
+   pub complete: bool
+}
+
+impl<T> Partial<T> {
+   pub fn new(input: T, complete: bool) -> Self {
+      Self { input, complete }
+   }
+}
+
+impl<T: Input> Input for Partial<T> {
+   fn len(&self) -> usize { self.input.len() }
+
+   fn chunk(&self, index: usize) -> &[u8] { self.input.chunk(index) }
+
+   fn slice(&self, start: usize, end: usize) -> Cow<'_, [u8]> { self.input.slice(start, end) }
+
+   fn unit_len(&self, index: usize) -> usize { self.input.unit_len(index) }
+
+   fn is_boundary(&self, index: usize) -> bool { self.input.is_boundary(index) }
+
+   fn is_text(&self) -> bool { self.input.is_text() }
+
+   fn is_partial(&self) -> bool { !self.complete }
+
+   fn text(&self, start: usize, end: usize) -> Cow<'_, str> { self.input.text(start, end) }
+}
+
+// a slice is only taken of data that was already read, it is complete
+impl<T: FromSlice<T>> FromSlice<Partial<T>> for Partial<T> {
+   fn from_slice(target: &Partial<T>, start: usize, end: usize) -> Self {
+      Partial::new(T::from_slice(&target.input, start, end), true)
+   }
+}
+
+/// # StreamError
+/// The failure of a [`StreamParser`], either reading the data or parsing it
+#[derive(Debug)]
+pub enum StreamError {
+   Io(io::Error),
+   /// A parse error, its index is an offset from the start of the stream
+   Parse(ParseError)
+}
+
+impl fmt::Display for StreamError {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      match self {
+         StreamError::Io(err) => write!(f, "failed to read the stream: {}", err),
+         StreamError::Parse(err) => write!(f, "{}", err)
+      }
+   }
+}
+
+impl Error for StreamError {
+   fn source(&self) -> Option<&(dyn Error + 'static)> {
+      match self {
+         StreamError::Io(err) => Some(err),
+         StreamError::Parse(err) => Some(err)
+      }
+   }
+}
+
+/// # StreamParser
+/// Runs `parser` repeatedly over the data pulled from a [`Read`], yielding
+/// one result per run (e.g. one record of a log). Only the data of the
+/// record being parsed is kept in memory: when the parser needs more of it
+/// more is read and the parser runs again from the start of the record.
+///
+/// The index and position of a run start at the beginning of its record,
+/// the index of a [`StreamError::Parse`] is an offset from the start of the
+/// stream. The iteration ends at the end of the stream or after an error
+///
+/// ### Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use parser_combinator::byte_parsers::{tag_parser::Tag, take_until_parser::TakeUntil};
+/// use parser_combinator::collection_parsers::sequence_of_parser::SequenceOf;
+/// use parser_combinator::streaming::StreamParser;
+///
+/// let line = SequenceOf::new(vec![
+///    Box::new(TakeUntil::new(b"\n")),
+///    Box::new(Tag::new(b"\n"))
+/// ]);
+/// let reader = Cursor::new("GET /\nPOST /upload\n");
+///
+/// let lines: Vec<Vec<u8>> = StreamParser::new(line, reader)
+///    .map(|line| line.unwrap().unwrap_many().remove(0))
+///    .collect();
+///
+/// assert_eq!(lines, vec![b"GET /".to_vec(), b"POST /upload".to_vec()]);
+/// ```
+pub struct StreamParser<P, R1, R2, Rd> {
+   parser: P,
+   reader: Rd,
+   buffer: Vec<u8>,
+   chunk_size: usize,
+   /// The offset in the stream of the start of `buffer`
+   offset: usize,
+   eof: bool,
+   done: bool,
+   _p1: PhantomData<R1>,
+   _p2: PhantomData<R2>,
+}
+
+impl<P, R1, R2, Rd> StreamParser<P, R1, R2, Rd>
+   where P: Parse<R1, R2, Partial<Vec<u8>>>, Rd: Read {
+   pub fn new(parser: P, reader: Rd) -> Self {
+      Self {
+         parser,
+         reader,
+         buffer: Vec::new(),
+         chunk_size: DEFAULT_CHUNK_SIZE,
+         offset: 0,
+         eof: false,
+         done: false,
+         _p1: PhantomData,
+         _p2: PhantomData
+      }
+   }
+
+   /// Read the stream `chunk_size` bytes at a time instead of [`DEFAULT_CHUNK_SIZE`]
+   pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+      self.chunk_size = chunk_size.max(1);
+      self
+   }
+
+   /// Append at least `needed` bytes to the buffer, unless the stream ends first
+   fn fill(&mut self, needed: usize) -> io::Result<()> {
+      let wanted = self.buffer.len() + needed;
+      let mut chunk = vec![0; needed.max(self.chunk_size)];
+
+      while self.buffer.len() < wanted {
+         match self.reader.read(&mut chunk) {
+            Ok(0) => {
+               self.eof = true;
+               break;
+            },
+            Ok(read) => self.buffer.extend_from_slice(&chunk[..read]),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {},
+            Err(err) => return Err(err)
+         }
+      }
+
+      Ok(())
+   }
+
+   fn fail(&mut self, err: StreamError) -> Option<Result<Cardinality<R2>, StreamError>> {
+      self.done = true;
+      Some(Err(err))
+   }
+}
+
+impl<P, R1, R2, Rd> Iterator for StreamParser<P, R1, R2, Rd>
+   where P: Parse<R1, R2, Partial<Vec<u8>>>, Rd: Read {
+   type Item = Result<Cardinality<R2>, StreamError>;
+
+   fn next(&mut self) -> Option<Self::Item> {
+      if self.done {
+         return None;
+      }
+
+      if self.buffer.is_empty() && !self.eof {
+         if let Err(err) = self.fill(1) {
+            return self.fail(StreamError::Io(err));
+         }
+      }
+
+      if self.buffer.is_empty() && self.eof {
+         self.done = true;
+         return None;
+      }
+
+      loop {
+         let target = Partial::new(mem::take(&mut self.buffer), self.eof);
+         let state = self.parser.run(target);
+
+         // the states of the run are gone, the buffer can be taken back
+         self.buffer = match Rc::try_unwrap(state.target) {
+            Ok(partial) => partial.input,
+            Err(partial) => partial.input.clone()
+         };
+
+         match state.result {
+            Some(Ok(value)) => {
+               // a parser consuming nothing would yield the same result forever
+               if state.index == 0 {
+                  self.done = true;
+               }
+
+               self.buffer.drain(..state.index);
+               self.offset += state.index;
+               return Some(Ok(value));
+            },
+            Some(Err(mut err)) => match err.kind {
+               ErrorKind::Incomplete(needed) if !self.eof => {
+                  if let Err(err) = self.fill(needed) {
+                     return self.fail(StreamError::Io(err));
+                  }
+               },
+               _ => {
+                  err.index += self.offset;
+                  return self.fail(StreamError::Parse(err));
+               }
+            },
+            None => panic!("StreamParser: parser returned an empty result")
+         }
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use std::io::{self, Cursor, Read};
+
+   use crate::collection_parsers::{choice_parser::Choice, sequence_of_parser::SequenceOf};
+   use crate::parsers::{digits_parser::Digits, letters_parser::Letters, str_parser::Str};
+   use super::{Partial, StreamError, StreamParser};
+
+   fn assignment() -> SequenceOf<String, String, Partial<Vec<u8>>> {
+      SequenceOf::new(vec![
+         Box::new(Letters::new()),
+         Box::new(Str::new("=".to_owned())),
+         Box::new(Digits::new()),
+         Box::new(Str::new(";".to_owned()))
+      ])
+   }
+
+   /// A reader handing over a single byte per read, like a slow socket
+   struct Trickle(Cursor<Vec<u8>>);
+
+   impl Read for Trickle {
+      fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+         let len = buf.len().min(1);
+         self.0.read(&mut buf[..len])
+      }
+   }
+
+   #[test]
+   fn resumes_across_reads() {
+      let reader = Trickle(Cursor::new(b"port=8080;host=1;retries=12345;".to_vec()));
+      let values: Vec<Vec<String>> = StreamParser::new(assignment(), reader)
+         .with_chunk_size(2)
+         .map(|res| res.unwrap().unwrap_many())
+         .collect();
+
+      assert_eq!(values.len(), 3);
+      assert_eq!(values[0], vec!["port", "=", "8080", ";"]);
+      assert_eq!(values[2][2], "12345");
+   }
+
+   #[test]
+   fn choice_waits_for_more_data() {
+      let parser = Choice::new(vec![Box::new(Str::new("ab".to_owned())), Box::new(Str::new("a".to_owned()))]);
+      let values: Vec<String> = StreamParser::new(parser, Trickle(Cursor::new(b"aba".to_vec())))
+         .with_chunk_size(1)
+         .map(|res| res.unwrap().unwrap_one())
+         .collect();
+
+      assert_eq!(values, vec!["ab", "a"]);
+   }
+
+   #[test]
+   fn error_index_is_stream_offset() {
+      let reader = Cursor::new(b"port=8080;host=x;".to_vec());
+      let mut stream = StreamParser::new(assignment(), reader).with_chunk_size(4);
+
+      assert!(stream.next().unwrap().is_ok());
+      match stream.next().unwrap() {
+         Err(StreamError::Parse(err)) => {
+            assert_eq!(err.parser, "Digits");
+            assert_eq!(err.index, 15);
+         },
+         res => panic!("unexpected result {:?}", res.map(|_| ()))
+      }
+      assert!(stream.next().is_none());
+   }
+
+   #[test]
+   fn truncated_stream_is_end_of_input() {
+      let reader = Cursor::new(b"port=80".to_vec());
+      let err = StreamParser::new(assignment(), reader).next().unwrap();
+
+      match err {
+         Err(StreamError::Parse(err)) => assert!(err.is_end_of_input()),
+         res => panic!("unexpected result {:?}", res.map(|_| ()))
+      }
+   }
+}
@@ -0,0 +1,208 @@
+
+pub mod token_kind_parser;
+pub mod token_satisfy_parser;
+
+use std::fmt::Debug;
+
+use crate::models::{parse_error::ParseError, position::Position};
+
+/// # Span
+/// The byte range of a token in the source text it was lexed from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+   pub start: usize,
+   pub end: usize
+}
+
+impl Span {
+   pub fn new(start: usize, end: usize) -> Self {
+      Self { start, end }
+   }
+}
+
+/// # Token
+/// A token produced by a user defined lexer. Token parsers run on a slice
+/// of tokens, `&[K]`, where the index of a state is the index of the next
+/// token rather than a byte offset.
+///
+/// The tokens do not carry the source text, so the `position` of a state
+/// stays at line 1, column 1. Use [`source_position`] for the line and
+/// column of a token and [`to_source_error`] to render an error
+pub trait Token: Clone + Debug {
+   /// The category of the token matched by [`token_kind_parser::TokenKind`]
+   type Kind: PartialEq + Clone + Debug;
+
+   fn kind(&self) -> Self::Kind;
+
+   /// The location of the token in the source text
+   fn span(&self) -> Span;
+}
+
+/// The source span of the token at `index`, an index past the last token
+/// maps to the empty span at the end of it
+pub fn source_span<K: Token>(tokens: &[K], index: usize) -> Span {
+   match tokens.get(index) {
+      Some(token) => token.span(),
+      None => {
+         let end = tokens.last().map(|token| token.span().end).unwrap_or(0);
+         Span::new(end, end)
+      }
+   }
+}
+
+/// The line and column in `source` of the token at `index`, `source` being
+/// the text the tokens were lexed from
+pub fn source_position<K: Token>(source: &str, tokens: &[K], index: usize) -> Position {
+   Position::from_index(source, source_span(tokens, index).start)
+}
+
+/// Map the token indexes of `err` and its causes back to byte offsets in
+/// the source text, so it can be rendered by [`crate::diagnostics::Diagnostic`]
+pub fn to_source_error<K: Token>(tokens: &[K], err: &ParseError) -> ParseError {
+   let mut mapped = err.clone();
+   mapped.index = source_span(tokens, err.index).start;
+   mapped.cause = err.cause
+      .as_deref()
+      .map(|cause| Box::new(to_source_error(tokens, cause)));
+
+   mapped
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::collection_parsers::{choice_parser::Choice, sep_by_parser::SepBy};
+   use crate::models::{
+      cardinality::Cardinality::{One, Many},
+      parser::Parser,
+      parser_traits::Parse,
+      position::Position,
+      state::State
+   };
+   use super::{Span, Token, source_position, to_source_error};
+   use super::{token_kind_parser::TokenKind, token_satisfy_parser::TokenSatisfy};
+
+   #[derive(Debug, Clone, Copy, PartialEq)]
+   enum Kind { Ident, Number, LParen, RParen, Comma }
+
+   #[derive(Debug, Clone)]
+   struct Tok {
+      kind: Kind,
+      text: String,
+      span: Span
+   }
+
+   impl Token for Tok {
+      type Kind = Kind;
+
+      fn kind(&self) -> Kind { self.kind }
+
+      fn span(&self) -> Span { self.span }
+   }
+
+   /// A minimal lexer splitting on punctuation and whitespace
+   fn lex(source: &str) -> Vec<Tok> {
+      let mut tokens = Vec::new();
+      let mut chars = source.char_indices().peekable();
+
+      while let Some((start, c)) = chars.next() {
+         let kind = match c {
+            '(' => Kind::LParen,
+            ')' => Kind::RParen,
+            ',' => Kind::Comma,
+            ' ' => continue,
+            c if c.is_ascii_digit() => Kind::Number,
+            _ => Kind::Ident
+         };
+
+         let mut end = start + c.len_utf8();
+         if matches!(kind, Kind::Ident | Kind::Number) {
+            while let Some((i, next)) = chars.peek().copied() {
+               if !next.is_alphanumeric() {
+                  break;
+               }
+               end = i + next.len_utf8();
+               chars.next();
+            }
+         }
+
+         tokens.push(Tok { kind, text: source[start..end].to_owned(), span: Span::new(start, end) });
+      }
+
+      tokens
+   }
+
+   /// `name(argument, ...)`. The combinators holding boxed parsers need
+   /// `'static` ones, the parts are run one after the other here so the
+   /// grammar can borrow a local slice of tokens
+   fn call<'a>() -> Parser<'a, Tok, Tok, &'a [Tok]> {
+      let name = TokenKind::new(Kind::Ident);
+      let open = TokenKind::new(Kind::LParen);
+      let argument = Choice::new(vec![
+         Box::new(TokenKind::new(Kind::Number)),
+         Box::new(TokenKind::new(Kind::Ident))
+      ]);
+      let arguments = SepBy::new(TokenKind::new(Kind::Comma), argument);
+      let close = TokenKind::new(Kind::RParen);
+
+      Parser::new(Box::new(move |state: State<Tok, &'a [Tok]>| {
+         let state = name.transform(state);
+         let mut values = match &state.result {
+            Some(Ok(One(token))) => vec![token.clone()],
+            _ => return state
+         };
+
+         let state = open.transform(state.reset());
+         if state.is_error() {
+            return state;
+         }
+
+         let state = arguments.transform(state.reset());
+         match &state.result {
+            Some(Ok(Many(tokens))) => values.extend(tokens.iter().cloned()),
+            _ => return state
+         }
+
+         let state = close.transform(state.reset());
+         if state.is_error() {
+            return state;
+         }
+
+         State { result: Some(Ok(Many(values))), ..state }
+      }))
+   }
+
+   #[test]
+   fn parses_tokens() {
+      let tokens = lex("max(1, x, 20)");
+      let result = call().run(&tokens);
+
+      let values: Vec<String> = result.result.unwrap().unwrap().unwrap_many()
+         .into_iter()
+         .map(|token| token.text)
+         .collect();
+
+      assert_eq!(values, vec!["max", "1", "x", "20"]);
+      assert_eq!(result.index, tokens.len());
+   }
+
+   #[test]
+   fn error_maps_to_source() {
+      let source = "max(1 x)";
+      let tokens = lex(source);
+      let err = call().run(&tokens).result.unwrap().unwrap_err();
+
+      assert_eq!(err.index, 3);
+      assert_eq!(to_source_error(&tokens, &err).index, 6);
+      assert_eq!(source_position(source, &tokens, err.index), Position { line: 1, column: 7 });
+   }
+
+   #[test]
+   fn satisfy_matches_value() {
+      let tokens = lex("let x");
+      let keyword = TokenSatisfy::new(|token: &Tok| token.text == "let");
+
+      assert_eq!(keyword.run(&tokens).index, 1);
+      assert!(keyword.run(&tokens[1..]).is_error());
+      assert!(keyword.run(&tokens[2..]).result.unwrap().unwrap_err().is_end_of_input());
+   }
+}
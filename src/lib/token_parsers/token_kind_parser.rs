@@ -0,0 +1,89 @@
+use std::fmt;
+
+use crate::models::{cardinality::Cardinality::One, parse_error::ParseError, parser_traits::Parse, state::State};
+
+use super::Token;
+
+/// # TokenKind:
+/// Parse the next token of a token slice if it is of the specified kind
+///
+/// ### Returns:
+/// The matched token
+///
+/// ### Examples
+///
+/// Basic Usage:
+///
+/// ```
+/// use parser_combinator::token_parsers::{Span, Token, token_kind_parser::TokenKind};
+/// use parser_combinator::models::parser_traits::Parse;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Kind { Ident, Equals }
+///
+/// #[derive(Debug, Clone)]
+/// struct Tok { kind: Kind, span: Span }
+///
+/// impl Token for Tok {
+///    type Kind = Kind;
+///    fn kind(&self) -> Kind { self.kind.clone() }
+///    fn span(&self) -> Span { self.span }
+/// }
+///
+/// let tokens = [
+///    Tok { kind: Kind::Ident, span: Span::new(0, 4) },
+///    Tok { kind: Kind::Equals, span: Span::new(5, 6) }
+/// ];
+/// let result = TokenKind::new(Kind::Ident).run(&tokens[..]);
+///
+/// assert_eq!(result.result.unwrap().unwrap().unwrap_one().span, Span::new(0, 4));
+/// assert_eq!(result.index, 1);
+/// ```
+pub struct TokenKind<K: Token> {
+   pub kind: K::Kind
+}
+
+impl<K: Token> TokenKind<K> {
+   pub fn new(kind: K::Kind) -> Self {
+      Self { kind }
+   }
+}
+
+impl<K: Token> fmt::Debug for TokenKind<K> {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      f.debug_struct("TokenKind").field("kind", &self.kind).finish()
+   }
+}
+
+impl<'s, K: Token> Parse<K, K, &'s [K]> for TokenKind<K> {
+   fn transform(&self, state: State<K, &'s [K]>) -> State<K, &'s [K]> {
+      if state.is_error() {
+         return state;
+      }
+
+      let expected = format!("{:?}", self.kind);
+
+      let token = match state.target.get(state.index) {
+         Some(token) => token.clone(),
+         None => {
+            let err = ParseError::end_of_input("TokenKind", state.index, expected);
+            return state.new_err(err);
+         }
+      };
+
+      if token.kind() != self.kind {
+         let found = format!("{:?}", token.kind());
+         let err = ParseError::mismatch("TokenKind", state.index, expected, found);
+         return state.new_err(err);
+      }
+
+      // the tokens carry no text to advance the position with, see Token
+      State {
+         index: state.index + 1,
+         target: state.target,
+         position: state.position,
+         result: Some(Ok(One(token))),
+         tracker: state.tracker
+      }
+   }
+}
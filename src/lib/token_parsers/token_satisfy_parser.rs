@@ -0,0 +1,63 @@
+use std::fmt;
+
+use crate::models::{cardinality::Cardinality::One, parse_error::ParseError, parser_traits::Parse, state::State};
+
+use super::Token;
+
+/// # TokenSatisfy:
+/// Parse the next token of a token slice if `predicate` holds for it, use
+/// it to match on the value of a token (e.g. a specific identifier).
+/// Wrap it in a [`crate::parsers::label_parser::Label`] to describe what
+/// was expected
+///
+/// ### Returns:
+/// The matched token
+pub struct TokenSatisfy<K: Token> {
+   predicate: Box<dyn Fn(&K) -> bool>
+}
+
+impl<K: Token> TokenSatisfy<K> {
+   pub fn new<F>(predicate: F) -> Self
+      where F: Fn(&K) -> bool + 'static {
+      Self { predicate: Box::new(predicate) }
+   }
+}
+
+impl<K: Token> fmt::Debug for TokenSatisfy<K> {
+   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+      f.debug_struct("TokenSatisfy").finish()
+   }
+}
+
+impl<'s, K: Token> Parse<K, K, &'s [K]> for TokenSatisfy<K> {
+   fn transform(&self, state: State<K, &'s [K]>) -> State<K, &'s [K]> {
+      if state.is_error() {
+         return state;
+      }
+
+      let expected = "a token matching the predicate".to_owned();
+
+      let token = match state.target.get(state.index) {
+         Some(token) => token.clone(),
+         None => {
+            let err = ParseError::end_of_input("TokenSatisfy", state.index, expected);
+            return state.new_err(err);
+         }
+      };
+
+      if !(self.predicate)(&token) {
+         let found = format!("{:?}", token.kind());
+         let err = ParseError::mismatch("TokenSatisfy", state.index, expected, found);
+         return state.new_err(err);
+      }
+
+      // the tokens carry no text to advance the position with, see Token
+      State {
+         index: state.index + 1,
+         target: state.target,
+         position: state.position,
+         result: Some(Ok(One(token))),
+         tracker: state.tracker
+      }
+   }
+}